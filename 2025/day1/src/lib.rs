@@ -0,0 +1,687 @@
+use aoc_utils::solution::Implementations;
+use aoc_utils::{Error, Solution, parse_lines_strict_str};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    fn step(&self) -> i32 {
+        match self {
+            Self::Left => -1,
+            Self::Right => 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Action {
+    direction: Direction,
+    distance: i32,
+    /// Which dial of a [`Lock`] to turn.
+    dial: usize,
+}
+
+impl Action {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+
+        // `L68` turns the first dial, `L68@2` the third
+        let (turn, dial) = match line.split_once('@') {
+            Some((turn, dial)) => (turn, dial.parse().ok()?),
+            None => (line, 0),
+        };
+
+        let ch = turn.chars().next()?;
+        let direction = Direction::from_char(ch)?;
+        let distance = turn[1..].parse().ok().filter(|&d| d >= 0)?;
+
+        Some(Self {
+            direction,
+            distance,
+            dial,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dial {
+    position: i32,
+    modulo: i32,
+}
+
+impl Dial {
+    fn new(starting_position: i32, modulo: i32) -> Self {
+        Self {
+            position: starting_position.rem_euclid(modulo),
+            modulo,
+        }
+    }
+
+    fn step(&mut self, direction: Direction) -> bool {
+        self.position = (self.position + direction.step()).rem_euclid(self.modulo);
+        self.position == 0
+    }
+
+    // when `action` points the dial at `mark`, without turning it
+    fn hits(&self, action: &Action, mark: i32) -> Hits {
+        let modulo = self.modulo as i64;
+        let gap = match action.direction {
+            Direction::Right => mark as i64 - self.position as i64,
+            Direction::Left => self.position as i64 - mark as i64,
+        };
+        // a full turn if it already points there
+        let first = match gap.rem_euclid(modulo) {
+            0 => modulo,
+            gap => gap,
+        };
+
+        let distance = action.distance as i64;
+        let count = if first > distance {
+            0
+        } else {
+            (distance - first) / modulo + 1
+        };
+
+        Hits {
+            first,
+            every: modulo,
+            count,
+        }
+    }
+
+    fn turn(&mut self, action: &Action) {
+        let end = self.position as i64 + action.direction.step() as i64 * action.distance as i64;
+        self.position = end.rem_euclid(self.modulo as i64) as i32;
+    }
+
+    // returns (1 if it ends at zero, times it passed zero before that), in
+    // constant time however far the dial turns
+    fn execute(&mut self, action: &Action) -> (i32, i32) {
+        let zeros = self.hits(action, 0).count as i32;
+        self.turn(action);
+
+        let ends_at_zero = if self.position == 0 { 1 } else { 0 };
+        (ends_at_zero, zeros - ends_at_zero)
+    }
+
+    // the same, one click at a time
+    fn execute_stepwise(&mut self, action: &Action) -> (i32, i32) {
+        let mut crosses_during = 0;
+
+        for _ in 0..action.distance {
+            if self.step(action.direction) {
+                crosses_during += 1;
+            }
+        }
+
+        if self.position == 0 {
+            // if we end at 0, it was counted in crosses_during
+            crosses_during -= 1;
+        }
+
+        (if self.position == 0 { 1 } else { 0 }, crosses_during)
+    }
+}
+
+/// The clicks of one turn at which a dial points at one position: `first`,
+/// `first + every`, and so on, `count` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hits {
+    first: i64,
+    every: i64,
+    count: i64,
+}
+
+/// How one dial of a [`Lock`] is set up, and which positions on it are
+/// worth reporting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialConfig {
+    pub modulo: i32,
+    pub start: i32,
+    pub marks: Vec<i32>,
+}
+
+impl DialConfig {
+    /// A dial that only reports zero.
+    pub fn new(modulo: i32, start: i32) -> Self {
+        Self {
+            modulo,
+            start,
+            marks: vec![0],
+        }
+    }
+
+    pub fn with_marks<I: IntoIterator<Item = i32>>(mut self, marks: I) -> Self {
+        self.marks = marks.into_iter().collect();
+        self
+    }
+}
+
+/// A dial pointing at one of its marks, `click` clicks into an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossing {
+    pub action: usize,
+    pub dial: usize,
+    pub mark: i32,
+    pub click: i64,
+    /// Whether this was the action's last click, leaving the dial there.
+    pub lands: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TraceEntry {
+    action: usize,
+    dial: usize,
+    mark: i32,
+    distance: i64,
+    hits: Hits,
+    /// Whether the dial ended the action on the mark, even without moving.
+    lands: bool,
+}
+
+/// Every crossing of a run, kept as arithmetic progressions so that long
+/// turns cost nothing until the crossings are listed.
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    entries: Vec<TraceEntry>,
+}
+
+impl Trace {
+    /// Every crossing, action by action and, within an action, mark by mark.
+    pub fn crossings(&self) -> impl Iterator<Item = Crossing> + '_ {
+        self.entries.iter().flat_map(|entry| {
+            (0..entry.hits.count).map(move |i| {
+                let click = entry.hits.first + i * entry.hits.every;
+                Crossing {
+                    action: entry.action,
+                    dial: entry.dial,
+                    mark: entry.mark,
+                    click,
+                    lands: click == entry.distance,
+                }
+            })
+        })
+    }
+
+    /// How many crossings there were.
+    pub fn count(&self) -> i64 {
+        self.entries.iter().map(|entry| entry.hits.count).sum()
+    }
+
+    /// How many times an action left a dial at a mark. A turn of zero on a
+    /// mark counts, though it crosses nothing.
+    pub fn landings(&self) -> i64 {
+        self.entries.iter().filter(|entry| entry.lands).count() as i64
+    }
+}
+
+/// Several dials turned by one list of actions.
+pub struct Lock {
+    dials: Vec<(Dial, Vec<i32>)>,
+}
+
+impl Lock {
+    /// Fails if a dial has no positions, i.e. its modulo isn't positive.
+    pub fn new(configs: &[DialConfig]) -> aoc_utils::Result<Self> {
+        if let Some((i, config)) = configs
+            .iter()
+            .enumerate()
+            .find(|(_, config)| config.modulo <= 0)
+        {
+            return Err(Error::shape(format!(
+                "dial {} has modulo {}, but needs at least one position",
+                i, config.modulo
+            )));
+        }
+
+        let dials = configs
+            .iter()
+            .map(|config| {
+                let dial = Dial::new(config.start, config.modulo);
+                (dial, config.marks.clone())
+            })
+            .collect();
+        Ok(Self { dials })
+    }
+
+    pub fn positions(&self) -> Vec<i32> {
+        self.dials.iter().map(|(dial, _)| dial.position).collect()
+    }
+
+    /// Applies every action in order. Fails, before turning anything, if an
+    /// action targets a dial the lock doesn't have.
+    pub fn run(&mut self, actions: &[Action]) -> aoc_utils::Result<Trace> {
+        if let Some((i, action)) = actions
+            .iter()
+            .enumerate()
+            .find(|(_, action)| action.dial >= self.dials.len())
+        {
+            return Err(Error::shape(format!(
+                "action {} turns dial {}, but the lock has {}",
+                i + 1,
+                action.dial,
+                self.dials.len()
+            )));
+        }
+
+        let mut trace = Trace::default();
+        for (i, action) in actions.iter().enumerate() {
+            let (dial, marks) = &mut self.dials[action.dial];
+            let before = *dial;
+            dial.turn(action);
+            for &mark in marks.iter() {
+                trace.entries.push(TraceEntry {
+                    action: i,
+                    dial: action.dial,
+                    mark,
+                    distance: action.distance as i64,
+                    hits: before.hits(action, mark),
+                    lands: dial.position == mark.rem_euclid(dial.modulo),
+                });
+            }
+        }
+
+        Ok(trace)
+    }
+}
+
+// the puzzle's lock: one dial of 100 starting at 50, watching zero
+fn puzzle_trace(actions: &[Action]) -> Trace {
+    Lock::new(&[DialConfig::new(100, 50)])
+        .and_then(|mut lock| lock.run(actions))
+        .expect("parse only accepts actions on the first dial")
+}
+
+fn part1(actions: &[Action]) -> i64 {
+    puzzle_trace(actions).landings()
+}
+
+fn part2(actions: &[Action]) -> i64 {
+    puzzle_trace(actions).count()
+}
+
+fn part1_dial(actions: &[Action]) -> i64 {
+    let mut dial = Dial::new(50, 100);
+    let mut count = 0;
+
+    for action in actions {
+        let (ends_at_zero, _) = dial.execute(action);
+        count += ends_at_zero as i64;
+    }
+
+    count
+}
+
+fn part2_dial(actions: &[Action]) -> i64 {
+    let mut dial = Dial::new(50, 100);
+    let mut count = 0;
+
+    for action in actions {
+        let (ends_at_zero, crosses_during) = dial.execute(action);
+        count += (ends_at_zero + crosses_during) as i64;
+    }
+
+    count
+}
+
+fn part1_stepwise(actions: &[Action]) -> i64 {
+    let mut dial = Dial::new(50, 100);
+    let mut count = 0;
+
+    for action in actions {
+        let (ends_at_zero, _) = dial.execute_stepwise(action);
+        count += ends_at_zero as i64;
+    }
+
+    count
+}
+
+fn part2_stepwise(actions: &[Action]) -> i64 {
+    let mut dial = Dial::new(50, 100);
+    let mut count = 0;
+
+    for action in actions {
+        let (ends_at_zero, crosses_during) = dial.execute_stepwise(action);
+        count += (ends_at_zero + crosses_during) as i64;
+    }
+
+    count
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Action>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        let actions = parse_lines_strict_str(input, "an action like L68", Action::parse)?;
+        if let Some(action) = actions.iter().find(|action| action.dial != 0) {
+            return Err(Error::shape(format!(
+                "the puzzle has one dial, but an action turns dial {}",
+                action.dial
+            )));
+        }
+        Ok(actions)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+
+    fn part1_implementations() -> Implementations<Self::Input, Self::Answer1> {
+        vec![
+            ("dial", |input| part1_dial(input)),
+            ("stepwise", |input| part1_stepwise(input)),
+        ]
+    }
+
+    fn part2_implementations() -> Implementations<Self::Input, Self::Answer2> {
+        vec![
+            ("dial", |input| part2_dial(input)),
+            ("stepwise", |input| part2_stepwise(input)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::testing::{Check, Rng};
+
+    aoc_utils::aoc_test!(Day1, file: "examples/1.txt");
+
+    #[test]
+    fn test_direction_from_char() {
+        assert_eq!(Direction::from_char('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('X'), None);
+    }
+
+    #[test]
+    fn test_action_parse() {
+        let action = Action::parse("L68").unwrap();
+        assert_eq!(action.direction, Direction::Left);
+        assert_eq!(action.distance, 68);
+
+        let action = Action::parse("R48").unwrap();
+        assert_eq!(action.direction, Direction::Right);
+        assert_eq!(action.distance, 48);
+
+        assert!(Action::parse("").is_none());
+        assert!(Action::parse("X10").is_none());
+
+        let action = Action::parse("R5@2").unwrap();
+        assert_eq!((action.distance, action.dial), (5, 2));
+        assert_eq!(Action::parse("L68").unwrap().dial, 0);
+        assert!(Action::parse("R5@x").is_none());
+    }
+
+    #[test]
+    fn test_dial_step() {
+        let mut dial = Dial::new(50, 100);
+
+        // step left from 50 to 49
+        assert!(!dial.step(Direction::Left));
+        assert_eq!(dial.position, 49);
+
+        // step right from 49 to 50
+        assert!(!dial.step(Direction::Right));
+        assert_eq!(dial.position, 50);
+    }
+
+    #[test]
+    fn test_dial_wrapping() {
+        let mut dial = Dial::new(0, 100);
+
+        // step left from 0 to 99
+        assert!(!dial.step(Direction::Left));
+        assert_eq!(dial.position, 99);
+
+        // step right from 99 to 0
+        assert!(dial.step(Direction::Right));
+        assert_eq!(dial.position, 0);
+    }
+
+    #[test]
+    fn test_part1_stepwise() {
+        let actions = Day1::parse(include_str!("../examples/1.txt")).unwrap();
+        assert_eq!(part1_stepwise(&actions), 3);
+    }
+
+    #[test]
+    fn test_part2_stepwise() {
+        let actions = Day1::parse(include_str!("../examples/1.txt")).unwrap();
+        assert_eq!(part2_stepwise(&actions), 6);
+    }
+
+    #[test]
+    fn test_execute_matches_stepwise() {
+        for start in [0, 1, 50, 99] {
+            for distance in [0, 1, 49, 50, 99, 100, 101, 250] {
+                for direction in [Direction::Left, Direction::Right] {
+                    let action = Action {
+                        direction,
+                        distance,
+                        dial: 0,
+                    };
+                    let mut stepped = Dial::new(start, 100);
+                    let mut dial = Dial::new(start, 100);
+                    assert_eq!(
+                        dial.execute(&action),
+                        stepped.execute_stepwise(&action),
+                        "{:?} from {}",
+                        action,
+                        start
+                    );
+                    assert_eq!(dial.position, stepped.position);
+                }
+            }
+        }
+    }
+
+    // ((start, signed distance), modulo), negative distances turning left
+    type Turn = ((i32, i32), i32);
+
+    fn random_turn(rng: &mut Rng) -> Turn {
+        let start = rng.between(0..=2_000) as i32 - 1_000;
+        let distance = rng.between(0..=20_000) as i32 - 10_000;
+        let modulo = rng.between(1..=150) as i32;
+        ((start, distance), modulo)
+    }
+
+    fn signed_action(distance: i32) -> Action {
+        let direction = if distance < 0 {
+            Direction::Left
+        } else {
+            Direction::Right
+        };
+        Action {
+            direction,
+            distance: distance.abs(),
+            dial: 0,
+        }
+    }
+
+    fn turn(&((start, distance), modulo): &Turn) -> ((i32, i32), i32) {
+        let mut dial = Dial::new(start, modulo);
+        let counts = dial.execute(&signed_action(distance));
+        (counts, dial.position)
+    }
+
+    fn turn_stepwise(&((start, distance), modulo): &Turn) -> ((i32, i32), i32) {
+        let mut dial = Dial::new(start, modulo);
+        let counts = dial.execute_stepwise(&signed_action(distance));
+        (counts, dial.position)
+    }
+
+    #[test]
+    fn test_execute_matches_stepwise_randomized() {
+        Check::new().differential(random_turn, turn, turn_stepwise);
+    }
+
+    #[test]
+    fn test_execute_long_turns() {
+        let mut dial = Dial::new(-30, 100);
+        assert_eq!(dial.position, 70);
+
+        // 70 -> 1_000_000_070 passes zero ten million times and stops at 70
+        let action = Action {
+            direction: Direction::Right,
+            distance: 1_000_000_000,
+            dial: 0,
+        };
+        assert_eq!(dial.execute(&action), (0, 10_000_000));
+        assert_eq!(dial.position, 70);
+
+        let action = Action {
+            direction: Direction::Left,
+            distance: 2_000_000_070,
+            dial: 0,
+        };
+        assert_eq!(dial.execute(&action), (1, 20_000_000));
+        assert_eq!(dial.position, 0);
+    }
+
+    #[test]
+    fn test_action_rejects_negative_distance() {
+        assert!(Action::parse("L-5").is_none());
+    }
+
+    fn actions(lines: &str) -> Vec<Action> {
+        lines.split_whitespace().filter_map(Action::parse).collect()
+    }
+
+    #[test]
+    fn test_lock_trace() {
+        let configs = [
+            DialConfig::new(10, 0).with_marks([0, 5]),
+            DialConfig::new(4, 1),
+        ];
+        let mut lock = Lock::new(&configs).unwrap();
+        let trace = lock.run(&actions("R12 L3@1 L7")).unwrap();
+        assert_eq!(lock.positions(), vec![5, 2]);
+
+        let crossings: Vec<_> = trace
+            .crossings()
+            .map(|c| (c.action, c.dial, c.mark, c.click, c.lands))
+            .collect();
+        assert_eq!(
+            crossings,
+            vec![
+                // 0 -> 12: passes 5, 0 and lands on 2
+                (0, 0, 0, 10, false),
+                (0, 0, 5, 5, false),
+                // 1 -> 2 on the second dial, passing 0
+                (1, 1, 0, 1, false),
+                // 2 -> 5 going down through 0
+                (2, 0, 0, 2, false),
+                (2, 0, 5, 7, true),
+            ]
+        );
+        assert_eq!(trace.count(), 5);
+        assert_eq!(trace.landings(), 1);
+    }
+
+    #[test]
+    fn test_lock_rejects_missing_dial() {
+        let mut lock = Lock::new(&[DialConfig::new(100, 50)]).unwrap();
+        let err = lock.run(&actions("R5 R5@1")).unwrap_err();
+        assert!(err.to_string().contains("action 2 turns dial 1"), "{}", err);
+        assert_eq!(lock.positions(), vec![50]);
+
+        assert!(Day1::parse("R5\nL3@1\n").is_err());
+        assert!(matches!(
+            Day1::parse("R5\nX10\n"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_lock_rejects_empty_dial() {
+        for modulo in [0, -4] {
+            let configs = [DialConfig::new(10, 0), DialConfig::new(modulo, 0)];
+            let err = Lock::new(&configs).err().unwrap();
+            assert!(matches!(err, Error::Shape(_)));
+            assert!(err.to_string().contains("dial 1 has modulo"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_lock_zero_turn_on_mark() {
+        let actions = actions("R50 R0 L0");
+        assert_eq!(part1(&actions), 3);
+        assert_eq!(part1_dial(&actions), 3);
+        assert_eq!(part1_stepwise(&actions), 3);
+        assert_eq!(part2(&actions), part2_stepwise(&actions));
+
+        let trace = puzzle_trace(&actions);
+        assert_eq!(trace.crossings().count(), 1);
+        assert_eq!(trace.landings(), 3);
+    }
+
+    #[test]
+    fn test_lock_matches_dial() {
+        Check::new().differential(
+            |rng| {
+                (0..rng.index(0..=20))
+                    .map(|_| {
+                        // whole half turns keep landing on zero, and zero
+                        // distances then stay there
+                        let distance = match rng.between(0..=1) {
+                            0 => 50 * rng.between(0..=4),
+                            _ => rng.between(0..=500),
+                        };
+                        (distance, rng.between(0..=1))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |turns: &Vec<(u64, u64)>| {
+                // (a, b) turns right by a when b is even, otherwise left by a
+                let actions = turns_to_actions(turns);
+                (part1(&actions), part2(&actions))
+            },
+            |turns: &Vec<(u64, u64)>| {
+                let actions = turns_to_actions(turns);
+                (part1_dial(&actions), part2_dial(&actions))
+            },
+        );
+    }
+
+    fn turns_to_actions(turns: &[(u64, u64)]) -> Vec<Action> {
+        turns
+            .iter()
+            .map(|&(distance, side)| Action {
+                direction: if side % 2 == 0 {
+                    Direction::Right
+                } else {
+                    Direction::Left
+                },
+                distance: distance as i32,
+                dial: 0,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,470 @@
+use aoc_utils::{Solution, parse_lines_split_strict_str};
+
+#[derive(Debug, PartialEq)]
+pub struct RangePair {
+    start: i64,
+    end: i64,
+}
+
+impl RangePair {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+
+        let (start_str, end_str) = line.split_once('-')?;
+        let start = start_str.parse().ok()?;
+        let end = end_str.parse().ok()?;
+        // println!("Parsed range pair: [{}, {}]", start, end);
+
+        Some(Self { start, end })
+    }
+
+    #[cfg(test)]
+    fn to_range(&self) -> std::ops::RangeInclusive<i64> {
+        self.start..=self.end
+    }
+}
+
+#[cfg(test)]
+fn is_invalid_id1(id: &i64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let half = len / 2;
+    let left = &s[..half];
+    let right = &s[half..];
+
+    left == right
+}
+
+#[cfg(test)]
+fn is_invalid_id2(id: &i64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    for pattern_len in 1..=len / 2 {
+        if len.is_multiple_of(pattern_len) {
+            let pattern = &s[..pattern_len];
+            if s.chars()
+                .collect::<Vec<_>>()
+                .chunks(pattern_len)
+                .all(|chunk| chunk.iter().collect::<String>() == pattern)
+            {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Which numbers count as one block of digits written several times over.
+/// Blocks can't start with a zero, and a block on its own never counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepetitionRule {
+    min_repeats: u32,
+    max_repeats: Option<u32>,
+    block_lens: Option<Vec<u32>>,
+    base: u32,
+}
+
+impl Default for RepetitionRule {
+    fn default() -> Self {
+        Self::at_least(2)
+    }
+}
+
+impl RepetitionRule {
+    /// A block written exactly `count` times, like 123123 for 2.
+    pub fn exactly(count: u32) -> Self {
+        Self {
+            max_repeats: Some(count),
+            ..Self::at_least(count)
+        }
+    }
+
+    /// A block written `count` or more times.
+    pub fn at_least(count: u32) -> Self {
+        Self {
+            min_repeats: count,
+            max_repeats: None,
+            block_lens: None,
+            base: 10,
+        }
+    }
+
+    /// Only blocks of these many digits.
+    pub fn with_block_lens<I: IntoIterator<Item = u32>>(mut self, lens: I) -> Self {
+        self.block_lens = Some(lens.into_iter().collect());
+        self
+    }
+
+    /// Digits in `base` rather than decimal, e.g. 16 or 2.
+    pub fn with_base(mut self, base: u32) -> Self {
+        assert!(base >= 2, "base {} has no digits to repeat", base);
+        self.base = base;
+        self
+    }
+
+    // whether `len` digits as `count` blocks is allowed
+    fn accepts(&self, len: u32, count: u32) -> bool {
+        count >= 2
+            && len.is_multiple_of(count)
+            && count >= self.min_repeats
+            && self.max_repeats.is_none_or(|max| count <= max)
+            && self
+                .block_lens
+                .as_ref()
+                .is_none_or(|lens| lens.contains(&(len / count)))
+    }
+
+    // in u128, so one more digit than any i64 has still fits
+    fn power(&self, exp: u32) -> u128 {
+        (self.base as u128).pow(exp)
+    }
+
+    fn digit_count(&self, mut n: u128) -> u32 {
+        let mut len = 1;
+        while n >= self.base as u128 {
+            n /= self.base as u128;
+            len += 1;
+        }
+        len
+    }
+
+    // a 1 at the start of every block: 123123 is 123 * 1001, 121212 is
+    // 12 * 10101
+    fn unit(&self, len: u32, count: u32) -> u128 {
+        (self.power(len) - 1) / (self.power(len / count) - 1)
+    }
+
+    pub fn matches(&self, id: i64) -> bool {
+        if id < 1 {
+            return false;
+        }
+        let id = id as u128;
+        let len = self.digit_count(id);
+        (2..=len).any(|count| self.accepts(len, count) && id.is_multiple_of(self.unit(len, count)))
+    }
+
+    /// Every match in `start..=end`, in increasing order. Works from the
+    /// block values rather than the range, so the range's width doesn't
+    /// matter.
+    pub fn matches_in(&self, start: i64, end: i64) -> Vec<i64> {
+        if end < start.max(1) {
+            return Vec::new();
+        }
+        let (start, end) = (start.max(1) as u128, end as u128);
+
+        let mut ids = Vec::new();
+        for len in self.digit_count(start)..=self.digit_count(end) {
+            // the part of the range with exactly `len` digits, so blocks
+            // can't start with a zero
+            let low = start.max(self.power(len - 1));
+            let high = end.min(self.power(len) - 1);
+
+            for count in (2..=len).filter(|&count| self.accepts(len, count)) {
+                let unit = self.unit(len, count);
+                let blocks = low.div_ceil(unit)..=high / unit;
+                ids.extend(blocks.map(|block| (block * unit) as i64));
+            }
+        }
+
+        // 1111 is both 1 four times and 11 twice
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+fn sum_matches(range_pairs: &[RangePair], rule: &RepetitionRule) -> i64 {
+    range_pairs
+        .iter()
+        .flat_map(|pair| rule.matches_in(pair.start, pair.end))
+        .sum()
+}
+
+fn part1(range_pairs: &[RangePair]) -> i64 {
+    sum_matches(range_pairs, &RepetitionRule::exactly(2))
+}
+
+fn part2(range_pairs: &[RangePair]) -> i64 {
+    sum_matches(range_pairs, &RepetitionRule::at_least(2))
+}
+
+// checking every id in every range, as a reference for the tests; its
+// time grows with the width of the ranges
+#[cfg(test)]
+fn part1_scan(range_pairs: &[RangePair]) -> i64 {
+    range_pairs
+        .iter()
+        .flat_map(|pair| pair.to_range())
+        .filter(is_invalid_id1)
+        .sum()
+}
+
+#[cfg(test)]
+fn part2_scan(range_pairs: &[RangePair]) -> i64 {
+    range_pairs
+        .iter()
+        .flat_map(|pair| pair.to_range())
+        .filter(is_invalid_id2)
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<RangePair>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_lines_split_strict_str(input, ",", "a range like 11-22", RangePair::parse)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::testing::{self, Check};
+
+    aoc_utils::aoc_test!(Day2, file: "examples/1.txt");
+
+    #[test]
+    fn test_range_pair_parse() {
+        let range = RangePair::parse("11-22").unwrap();
+        assert_eq!(range.start, 11);
+        assert_eq!(range.end, 22);
+
+        let range = RangePair::parse("1188511880-1188511890").unwrap();
+        assert_eq!(range.start, 1188511880);
+        assert_eq!(range.end, 1188511890);
+
+        assert!(RangePair::parse("").is_none());
+        assert!(RangePair::parse("blah").is_none());
+
+        let err = Day2::parse("11-22,blah,95-115\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at 1:7: expected a range like 11-22, found \"blah\""
+        );
+    }
+
+    #[test]
+    fn test_range_pair_parse_multiple() {
+        let input = "11-22,95-115,998-1012";
+
+        let range_pairs: Vec<RangePair> = input.split(",").filter_map(RangePair::parse).collect();
+
+        assert_eq!(range_pairs.len(), 3);
+
+        assert_eq!(range_pairs[0].start, 11);
+        assert_eq!(range_pairs[0].end, 22);
+
+        assert_eq!(range_pairs[1].start, 95);
+        assert_eq!(range_pairs[1].end, 115);
+
+        assert_eq!(range_pairs[2].start, 998);
+        assert_eq!(range_pairs[2].end, 1012);
+    }
+
+    #[test]
+    fn test_is_invalid_id1() {
+        assert!(is_invalid_id1(&11));
+        assert!(is_invalid_id1(&22));
+        assert!(is_invalid_id1(&6464));
+        assert!(is_invalid_id1(&123123));
+        assert!(is_invalid_id1(&1010));
+        assert!(is_invalid_id1(&1188511885));
+        assert!(is_invalid_id1(&222222));
+        assert!(is_invalid_id1(&446446));
+        assert!(is_invalid_id1(&38593859));
+
+        assert!(!is_invalid_id1(&10));
+        assert!(!is_invalid_id1(&101));
+        assert!(!is_invalid_id1(&35353));
+    }
+
+    #[test]
+    fn test_is_invalid_id2() {
+        assert!(is_invalid_id2(&11));
+        assert!(is_invalid_id2(&22));
+        assert!(is_invalid_id2(&99));
+        assert!(is_invalid_id2(&111));
+        assert!(is_invalid_id2(&999));
+        assert!(is_invalid_id2(&6464));
+        assert!(is_invalid_id2(&123123));
+        assert!(is_invalid_id2(&1010));
+        assert!(is_invalid_id2(&1188511885));
+        assert!(is_invalid_id2(&222222));
+        assert!(is_invalid_id2(&2121212121));
+        assert!(is_invalid_id2(&446446));
+        assert!(is_invalid_id2(&353535));
+        assert!(is_invalid_id2(&38593859));
+
+        assert!(!is_invalid_id2(&12));
+        assert!(!is_invalid_id2(&123));
+        assert!(!is_invalid_id2(&1234));
+        assert!(!is_invalid_id2(&100));
+        assert!(!is_invalid_id2(&1698522));
+    }
+
+    #[test]
+    fn test_invalid_id1_implies_invalid_id2() {
+        Check::new().property(
+            |rng| testing::ranges(rng, 1..=4, 10_000_000_000, 2_000),
+            |ranges| {
+                ranges
+                    .iter()
+                    .flat_map(|&(start, end)| start as i64..=end as i64)
+                    .all(|id| !is_invalid_id1(&id) || is_invalid_id2(&id))
+            },
+        );
+    }
+
+    #[test]
+    fn test_matches_in() {
+        assert_eq!(RepetitionRule::exactly(2).matches_in(11, 22), vec![11, 22]);
+        // crossing from two to three to four digits
+        assert_eq!(RepetitionRule::exactly(2).matches_in(95, 115), vec![99]);
+        assert_eq!(RepetitionRule::default().matches_in(95, 115), vec![99, 111]);
+        assert_eq!(
+            RepetitionRule::default().matches_in(998, 1012),
+            vec![999, 1010]
+        );
+        assert_eq!(
+            RepetitionRule::exactly(2).matches_in(1, 1_000_000).len(),
+            999
+        );
+
+        // 111111 is 1 six times, 11 three times and 111 twice
+        assert_eq!(
+            RepetitionRule::default().matches_in(111_111, 111_111),
+            vec![111_111]
+        );
+
+        assert!(RepetitionRule::default().matches_in(22, 11).is_empty());
+        assert!(RepetitionRule::default().matches_in(-5, 10).is_empty());
+    }
+
+    #[test]
+    fn test_matches_in_huge_range() {
+        let ids = RepetitionRule::exactly(9).matches_in(1, i64::MAX);
+        // nine digits of one block is the only way to use nine repeats below
+        // 19 digits, plus 18 digits as nine blocks of two
+        assert_eq!(ids.len(), 9 + 90);
+        assert_eq!(ids[0], 111_111_111);
+        assert_eq!(*ids.last().unwrap(), 999_999_999_999_999_999);
+
+        // 15 digits can only be blocks of 1, 3 or 5, and blocks of 1 are
+        // also blocks of 3 and 5
+        let ids = RepetitionRule::default().matches_in(100_000_000_000_000, 999_999_999_999_999);
+        assert_eq!(ids.len(), 900 + 90_000 - 9);
+        assert!(ids.iter().all(is_invalid_id2));
+    }
+
+    #[test]
+    fn test_matches_in_matches_scan() {
+        Check::new().differential(
+            |rng| testing::ranges(rng, 1..=4, 10_000_000_000, 2_000),
+            |ranges: &Vec<(u64, u64)>| {
+                let pairs = to_pairs(ranges);
+                (part1(&pairs), part2(&pairs))
+            },
+            |ranges: &Vec<(u64, u64)>| {
+                let pairs = to_pairs(ranges);
+                (part1_scan(&pairs), part2_scan(&pairs))
+            },
+        );
+    }
+
+    #[test]
+    fn test_rule_matches() {
+        let halves = RepetitionRule::exactly(2);
+        let any = RepetitionRule::default();
+        for id in [11, 6464, 123123, 1010, 1188511885, 0, 10, 101, 35353, 111] {
+            assert_eq!(halves.matches(id), is_invalid_id1(&id), "{}", id);
+        }
+        for id in [111, 999, 2121212121, 353535, 12, 100, 1698522, -11] {
+            assert_eq!(any.matches(id), is_invalid_id2(&id), "{}", id);
+        }
+
+        let triples = RepetitionRule::at_least(3);
+        assert!(triples.matches(121212));
+        assert!(triples.matches(111111));
+        assert!(!triples.matches(123123));
+
+        let pairs = RepetitionRule::default().with_block_lens([2]);
+        assert!(pairs.matches(121212));
+        // 1111 is 11 twice as well as 1 four times
+        assert!(pairs.matches(1111));
+        assert!(!pairs.matches(123123));
+        assert!(!pairs.matches(111));
+    }
+
+    #[test]
+    fn test_rule_bases() {
+        let hex = RepetitionRule::exactly(2).with_base(16);
+        assert!(hex.matches(0xABAB));
+        assert!(hex.matches(0x1010));
+        assert!(!hex.matches(1010));
+        assert_eq!(hex.matches_in(0x10, 0x30), vec![0x11, 0x22]);
+
+        let binary = RepetitionRule::default().with_base(2);
+        assert!(binary.matches(0b1010));
+        assert!(binary.matches(0b111));
+        assert!(!binary.matches(0b100));
+        assert_eq!(binary.matches_in(1, 16), vec![0b11, 0b111, 0b1010, 0b1111]);
+    }
+
+    #[test]
+    fn test_matches_in_agrees_with_matches() {
+        let rules = [
+            RepetitionRule::exactly(2).with_base(2),
+            RepetitionRule::at_least(3).with_base(16),
+            RepetitionRule::default().with_block_lens([1, 3]),
+            RepetitionRule::exactly(4).with_base(3),
+        ];
+        Check::new().property(
+            |rng| testing::ranges(rng, 1..=2, 1_000_000, 1_000),
+            |ranges| {
+                rules.iter().all(|rule| {
+                    ranges.iter().all(|&(start, end)| {
+                        let (start, end) = (start as i64, end as i64);
+                        let scanned: Vec<_> =
+                            (start..=end).filter(|&id| rule.matches(id)).collect();
+                        rule.matches_in(start, end) == scanned
+                    })
+                })
+            },
+        );
+    }
+
+    fn to_pairs(ranges: &[(u64, u64)]) -> Vec<RangePair> {
+        ranges
+            .iter()
+            .map(|&(start, end)| RangePair {
+                start: start as i64,
+                end: end as i64,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,582 @@
+use aoc_utils::solution::Implementations;
+use aoc_utils::{Solution, parse_lines_str};
+use std::collections::VecDeque;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct PowerBank {
+    batteries: Vec<u32>,
+}
+
+impl PowerBank {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+
+        let joltages = line.chars().filter_map(|c| c.to_digit(10)).collect();
+
+        Some(Self {
+            batteries: joltages,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.batteries.len()
+    }
+}
+
+// O(n^2), O(1)
+fn max_joltage_brute_force(power_bank: &PowerBank) -> u32 {
+    let mut max_joltage = 0;
+
+    for i in 0..power_bank.len() {
+        for j in (i + 1)..power_bank.len() {
+            let val = power_bank.batteries[i] * 10 + power_bank.batteries[j];
+            max_joltage = max_joltage.max(val);
+        }
+    }
+
+    max_joltage
+}
+
+// O(n), O(n)
+fn max_joltage_greedy(power_bank: &PowerBank) -> u32 {
+    let mut max_joltage = 0;
+
+    let mut suffix_max = vec![0; power_bank.len()];
+    for i in (0..power_bank.len() - 1).rev() {
+        suffix_max[i] = suffix_max[i + 1].max(power_bank.batteries[i + 1]);
+    }
+
+    let last = power_bank.len() - 1;
+    for (battery, max_after) in power_bank.batteries[..last].iter().zip(&suffix_max) {
+        let curr = battery * 10 + max_after;
+        max_joltage = max_joltage.max(curr);
+    }
+
+    max_joltage
+}
+
+fn max_joltage_k_digits(power_bank: &PowerBank, k: usize) -> u64 {
+    let n = power_bank.batteries.len();
+
+    if k == 0 || k > n {
+        return 0;
+    }
+
+    let mut stack: Vec<u32> = Vec::new();
+
+    for (i, &digit) in power_bank.batteries.iter().enumerate() {
+        // see if we can remove smaller digits
+        while !stack.is_empty() && stack.last().unwrap() < &digit && stack.len() + (n - i) > k {
+            stack.pop();
+        }
+
+        if stack.len() < k {
+            stack.push(digit);
+        }
+    }
+
+    stack
+        .iter()
+        .fold(0u64, |acc, &digit| acc * 10 + digit as u64)
+
+    // max_joltage
+}
+
+/// Whether a [`Selector`] is after the largest or the smallest number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pick {
+    Largest,
+    Smallest,
+}
+
+/// How to choose `len` digits, keeping their order, out of a longer row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    len: usize,
+    pick: Pick,
+    min_gap: usize,
+    base: u32,
+}
+
+impl Selector {
+    pub fn largest(len: usize) -> Self {
+        Self {
+            len,
+            pick: Pick::Largest,
+            min_gap: 1,
+            base: 10,
+        }
+    }
+
+    pub fn smallest(len: usize) -> Self {
+        Self {
+            pick: Pick::Smallest,
+            ..Self::largest(len)
+        }
+    }
+
+    /// Chosen positions at least `gap` apart; 1, the default, allows
+    /// neighbours.
+    pub fn with_min_gap(mut self, gap: usize) -> Self {
+        assert!(gap >= 1, "positions can't be chosen twice");
+        self.min_gap = gap;
+        self
+    }
+
+    /// Digits in `base` rather than decimal.
+    pub fn with_base(mut self, base: u32) -> Self {
+        assert!(base >= 2, "base {} has no digits", base);
+        self.base = base;
+        self
+    }
+
+    fn better(&self, a: u32, b: u32) -> bool {
+        match self.pick {
+            Pick::Largest => a > b,
+            Pick::Smallest => a < b,
+        }
+    }
+
+    /// The best choice, taking the earliest position on ties. `None` if
+    /// `digits` is too short to fit `len` picks, or holds something that
+    /// isn't a digit in the base. O(n) for any `len`.
+    pub fn select(&self, digits: &[u32]) -> Option<Subsequence> {
+        if digits.iter().any(|&digit| digit >= self.base) {
+            return None;
+        }
+        // the first and last picks are this far apart at the least, which
+        // may not even fit in a usize
+        if self.len > 0 && (self.len - 1).checked_mul(self.min_gap)? >= digits.len() {
+            return None;
+        }
+
+        // the candidates for the next pick, best and earliest at the front
+        let mut window: VecDeque<usize> = VecDeque::new();
+        let mut indices = Vec::with_capacity(self.len);
+        let mut next = 0;
+        let mut low = 0;
+        for picked in 0..self.len {
+            // leave room for the picks after this one
+            let high = digits.len() - 1 - (self.len - 1 - picked).checked_mul(self.min_gap)?;
+            while next <= high {
+                // equal digits stay, so the earliest one wins
+                while window
+                    .back()
+                    .is_some_and(|&i| self.better(digits[next], digits[i]))
+                {
+                    window.pop_back();
+                }
+                window.push_back(next);
+                next += 1;
+            }
+            while window.front().is_some_and(|&i| i < low) {
+                window.pop_front();
+            }
+
+            let i = window.pop_front().expect("low <= high keeps a candidate");
+            indices.push(i);
+            low = i + self.min_gap;
+        }
+
+        Some(Subsequence {
+            digits: indices.iter().map(|&i| digits[i]).collect(),
+            indices,
+            base: self.base,
+        })
+    }
+}
+
+/// Digits chosen by a [`Selector`], most significant first, and where in
+/// the row each came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subsequence {
+    pub digits: Vec<u32>,
+    pub indices: Vec<usize>,
+    base: u32,
+}
+
+impl Subsequence {
+    /// The value, or `None` if it doesn't fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.digits.iter().try_fold(0u64, |acc, &digit| {
+            acc.checked_mul(self.base as u64)?.checked_add(digit as u64)
+        })
+    }
+
+    pub fn to_big(&self) -> BigUint {
+        let mut value = BigUint::default();
+        for &digit in &self.digits {
+            value.mul_add(self.base, digit);
+        }
+        value
+    }
+}
+
+const LIMB: u64 = 1_000_000_000;
+
+/// An unsigned integer of any size, as decimal limbs of nine digits, least
+/// significant first. Only what [`Subsequence::to_big`] needs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn mul_add(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in &mut self.limbs {
+            let value = *limb as u64 * mul as u64 + carry;
+            *limb = (value % LIMB) as u32;
+            carry = value / LIMB;
+        }
+        while carry > 0 {
+            self.limbs.push((carry % LIMB) as u32);
+            carry /= LIMB;
+        }
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{}", top)?;
+        for limb in rest.iter().rev() {
+            write!(f, "{:09}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+fn part1(power_banks: &[PowerBank]) -> u32 {
+    power_banks.iter().map(max_joltage_greedy).sum()
+}
+
+fn part1_brute_force(power_banks: &[PowerBank]) -> u32 {
+    power_banks.iter().map(max_joltage_brute_force).sum()
+}
+
+fn part1_stack(power_banks: &[PowerBank]) -> u32 {
+    power_banks
+        .iter()
+        .map(|pb| max_joltage_k_digits(pb, 2) as u32)
+        .sum()
+}
+
+fn part2(power_banks: &[PowerBank]) -> u64 {
+    let selector = Selector::largest(12);
+    power_banks
+        .iter()
+        .filter_map(|pb| selector.select(&pb.batteries))
+        .map(|chosen| chosen.to_u64().expect("12 decimal digits fit a u64"))
+        .sum()
+}
+
+fn part2_stack(power_banks: &[PowerBank]) -> u64 {
+    power_banks
+        .iter()
+        .map(|pb| max_joltage_k_digits(pb, 12))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<PowerBank>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        Ok(parse_lines_str(input, PowerBank::parse))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+
+    fn part1_implementations() -> Implementations<Self::Input, Self::Answer1> {
+        vec![
+            ("brute force", |input| part1_brute_force(input)),
+            ("stack", |input| part1_stack(input)),
+        ]
+    }
+
+    fn part2_implementations() -> Implementations<Self::Input, Self::Answer2> {
+        vec![("stack", |input| part2_stack(input))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::testing::{self, Check};
+
+    aoc_utils::aoc_test!(Day3, file: "examples/1.txt");
+
+    fn get_test_power_banks_part_1() -> Vec<(PowerBank, u32)> {
+        vec![
+            (
+                PowerBank {
+                    batteries: vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                },
+                98,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                },
+                89,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                },
+                78,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+                },
+                92,
+            ),
+        ]
+    }
+
+    fn get_test_power_banks_part_2() -> Vec<(PowerBank, u64)> {
+        vec![
+            (
+                PowerBank {
+                    batteries: vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+                },
+                987654321111,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+                },
+                811111111119,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+                },
+                434234234278,
+            ),
+            (
+                PowerBank {
+                    batteries: vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+                },
+                888911112111,
+            ),
+        ]
+    }
+
+    fn parse_test_input() -> Vec<PowerBank> {
+        Day3::parse(include_str!("../examples/1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_battery_parse() {
+        let power_banks = parse_test_input();
+        for b in power_banks {
+            assert_eq!(b.len(), 15);
+        }
+    }
+
+    #[test]
+    fn test_max_joltage_brute_force() {
+        let test_power_banks = get_test_power_banks_part_1();
+        for (input, expected) in test_power_banks {
+            let result = max_joltage_brute_force(&input);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_max_joltage_greedy() {
+        let test_power_banks = get_test_power_banks_part_1();
+        for (input, expected) in test_power_banks {
+            let result = max_joltage_greedy(&input);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_max_joltage_n() {
+        let test_power_banks = get_test_power_banks_part_1();
+        for (input, expected) in test_power_banks {
+            let result = max_joltage_k_digits(&input, 2);
+            assert_eq!(result as u32, expected);
+        }
+
+        let test_power_banks = get_test_power_banks_part_2();
+        for (input, expected) in test_power_banks {
+            let result = max_joltage_k_digits(&input, 12);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_greedy_matches_k_digits() {
+        Check::new().differential(
+            |rng| testing::digits(rng, 2..=40, 1..=9),
+            |batteries| {
+                max_joltage_greedy(&PowerBank {
+                    batteries: batteries.clone(),
+                }) as u64
+            },
+            |batteries| {
+                max_joltage_k_digits(
+                    &PowerBank {
+                        batteries: batteries.clone(),
+                    },
+                    2,
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_selector_examples() {
+        for (input, expected) in get_test_power_banks_part_2() {
+            let chosen = Selector::largest(12).select(&input.batteries).unwrap();
+            assert_eq!(chosen.to_u64(), Some(expected));
+        }
+
+        let row = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+        let chosen = Selector::largest(4).select(&row).unwrap();
+        assert_eq!(chosen.digits, vec![9, 2, 1, 1]);
+        assert_eq!(chosen.indices, vec![6, 11, 12, 13]);
+
+        let chosen = Selector::smallest(4).select(&row).unwrap();
+        assert_eq!(chosen.digits, vec![1, 1, 1, 1]);
+        assert_eq!(chosen.indices, vec![1, 3, 5, 7]);
+
+        assert!(Selector::largest(16).select(&row).is_none());
+        assert!(Selector::largest(1).select(&[1, 10]).is_none());
+        assert_eq!(Selector::largest(0).select(&row).unwrap().to_u64(), Some(0));
+    }
+
+    #[test]
+    fn test_selector_min_gap() {
+        let row = [9, 9, 1, 8, 7];
+        let chosen = Selector::largest(2).select(&row).unwrap();
+        assert_eq!(chosen.indices, vec![0, 1]);
+
+        let chosen = Selector::largest(2).with_min_gap(2).select(&row).unwrap();
+        assert_eq!((chosen.digits, chosen.indices), (vec![9, 8], vec![0, 3]));
+
+        let chosen = Selector::largest(3).with_min_gap(2).select(&row).unwrap();
+        assert_eq!(chosen.indices, vec![0, 2, 4]);
+        assert!(Selector::largest(3).with_min_gap(3).select(&row).is_none());
+
+        // the span of the picks overflows rather than merely exceeding the row
+        let huge = Selector::largest(3).with_min_gap(usize::MAX);
+        assert!(huge.select(&row).is_none());
+        let single = Selector::largest(1).with_min_gap(usize::MAX);
+        assert_eq!(single.select(&row).unwrap().indices.len(), 1);
+    }
+
+    #[test]
+    fn test_selector_bases_and_big_values() {
+        let chosen = Selector::largest(2)
+            .with_base(16)
+            .select(&[15, 0, 10])
+            .unwrap();
+        assert_eq!(chosen.to_u64(), Some(0xFA));
+        assert_eq!(chosen.to_big().to_string(), "250");
+
+        let chosen = Selector::largest(3)
+            .with_base(2)
+            .select(&[1, 0, 1, 1])
+            .unwrap();
+        assert_eq!(chosen.to_u64(), Some(0b111));
+
+        let row: Vec<u32> = (0..40).map(|i| 9 - i % 10).collect();
+        let chosen = Selector::largest(40).select(&row).unwrap();
+        assert_eq!(chosen.to_u64(), None);
+        assert_eq!(chosen.to_big().to_string(), "9876543210".repeat(4));
+        assert_eq!(BigUint::default().to_string(), "0");
+    }
+
+    // every choice of `len` positions `gap` apart, best first, earliest on ties
+    fn select_brute_force(row: &[u32], len: usize, gap: usize, pick: Pick) -> Option<Vec<usize>> {
+        fn choices(row_len: usize, from: usize, len: usize, gap: usize) -> Vec<Vec<usize>> {
+            if len == 0 {
+                return vec![Vec::new()];
+            }
+            (from..row_len)
+                .flat_map(|i| {
+                    choices(row_len, i + gap, len - 1, gap)
+                        .into_iter()
+                        .map(move |mut rest| {
+                            rest.insert(0, i);
+                            rest
+                        })
+                })
+                .collect()
+        }
+
+        let digits = |indices: &Vec<usize>| indices.iter().map(|&i| row[i]).collect::<Vec<_>>();
+        choices(row.len(), 0, len, gap).into_iter().min_by(|a, b| {
+            let by_digits = digits(a).cmp(&digits(b));
+            let by_digits = match pick {
+                Pick::Largest => by_digits.reverse(),
+                Pick::Smallest => by_digits,
+            };
+            by_digits.then(a.cmp(b))
+        })
+    }
+
+    #[test]
+    fn test_selector_matches_brute_force() {
+        Check::new().property(
+            |rng| {
+                let row = testing::digits(rng, 0..=10, 0..=9);
+                (row, (rng.index(0..=5), rng.index(0..=2)))
+            },
+            |(row, (len, extra_gap))| {
+                [Pick::Largest, Pick::Smallest].into_iter().all(|pick| {
+                    let selector = Selector {
+                        pick,
+                        ..Selector::largest(*len).with_min_gap(extra_gap + 1)
+                    };
+                    let chosen = selector.select(row).map(|chosen| chosen.indices);
+                    chosen == select_brute_force(row, *len, extra_gap + 1, pick)
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn test_selector_matches_k_digits() {
+        Check::new().differential(
+            |rng| (testing::digits(rng, 1..=40, 1..=9), rng.index(1..=19)),
+            |(batteries, k)| {
+                Selector::largest(*k)
+                    .select(batteries)
+                    .map_or(0, |chosen| chosen.to_u64().unwrap())
+            },
+            |(batteries, k)| {
+                max_joltage_k_digits(
+                    &PowerBank {
+                        batteries: batteries.clone(),
+                    },
+                    *k,
+                )
+            },
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_utils::{Solution, parse_grid_str};
+use std::collections::HashSet;
+
+type Coord = (usize, usize);
+type Grid = aoc_utils::Grid<Point>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point {
+    x: usize,
+    y: usize,
+    value: char,
+}
+
+impl Point {
+    fn new(x: usize, y: usize, value: char) -> Self {
+        Self { x, y, value }
+    }
+
+    fn coords(&self) -> Coord {
+        (self.x, self.y)
+    }
+
+    fn is_roll(&self) -> bool {
+        self.value == '@'
+    }
+}
+
+fn count_neighbors(point: &Point, grid: &Grid, removed: &HashSet<Coord>) -> usize {
+    grid.neighbors8(point.x, point.y)
+        .filter(|&coord| grid[coord].is_roll() && !removed.contains(&coord))
+        .count()
+}
+
+fn find_accessible(grid: &Grid, removed: &HashSet<Coord>) -> Vec<Coord> {
+    grid.iter()
+        .map(|(_, point)| point)
+        .filter(|point| point.is_roll() && !removed.contains(&point.coords()))
+        .filter(|point| count_neighbors(point, grid, removed) < 4)
+        .map(|point| point.coords())
+        .collect()
+}
+
+fn forkliftable(grid: &Grid, should_remove: bool) -> usize {
+    let mut result = 0;
+    let mut removed = HashSet::new();
+
+    loop {
+        let accessible = find_accessible(grid, &removed);
+
+        if accessible.is_empty() {
+            break;
+        }
+
+        result += accessible.len();
+
+        if !should_remove {
+            break;
+        }
+
+        removed.extend(accessible);
+    }
+
+    result
+}
+
+fn part1(grid: &Grid) -> usize {
+    forkliftable(grid, false)
+}
+
+fn part2(grid: &Grid) -> usize {
+    forkliftable(grid, true)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_grid_str(input, Point::new)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::aoc_test!(Day4, file: "examples/1.txt");
+
+    fn parse_test_input() -> Grid {
+        Day4::parse(include_str!("../examples/1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse_test_input();
+        assert_eq!(grid.height(), 10);
+        assert_eq!(grid.width(), 10);
+
+        assert_eq!(grid[(0, 0)].value, '.');
+        assert_eq!(grid[(0, 0)].coords(), (0, 0));
+
+        assert_eq!(grid[(0, 2)].value, '@');
+        assert!(grid[(0, 2)].is_roll());
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_utils::intervals::IntervalSet;
+use aoc_utils::{Error, Solution, parse_sections_split_str};
+use std::ops::RangeInclusive;
+
+type IngredientId = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FreshRange {
+    start: IngredientId,
+    end: IngredientId,
+}
+
+impl FreshRange {
+    fn new(start: IngredientId, end: IngredientId) -> Self {
+        Self { start, end }
+    }
+
+    /// Parses `start-end`, on line `line_number` of the input.
+    fn parse(line_number: usize, line: &str) -> aoc_utils::Result<Self> {
+        let text = line.trim();
+        let column = line.len() - line.trim_start().len() + 1;
+        let bad = |offset, found| Error::parse(line_number, column + offset, found, "start-end");
+
+        let (low, high) = text.split_once('-').ok_or_else(|| bad(0, text))?;
+        let start = low.parse().map_err(|_| bad(0, low))?;
+        let end = high.parse().map_err(|_| bad(low.len() + 1, high))?;
+        Ok(Self::new(start, end))
+    }
+
+    fn to_range(self) -> RangeInclusive<IngredientId> {
+        self.start..=self.end
+    }
+}
+
+// non-blank lines of a section, with their line numbers in the whole input
+fn numbered(lines: &[String], first: usize) -> impl Iterator<Item = (usize, &str)> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(i, line)| (first + i, line.as_str()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Database {
+    fresh: IntervalSet<IngredientId>,
+    available_ids: Vec<IngredientId>,
+}
+
+impl Database {
+    fn new(fresh_ranges: Vec<FreshRange>, available_ids: Vec<IngredientId>) -> Self {
+        let fresh = fresh_ranges.into_iter().map(FreshRange::to_range).collect();
+        Self {
+            fresh,
+            available_ids,
+        }
+    }
+
+    fn parse(sections: Vec<Vec<String>>) -> aoc_utils::Result<Self> {
+        if sections.len() != 2 {
+            return Err(Error::shape(format!(
+                "expected 2 sections, got {}",
+                sections.len()
+            )));
+        }
+
+        // the ID section starts after the ranges and the blank line
+        let ids_line = sections[0].len() + 2;
+        let fresh_ranges = numbered(&sections[0], 1)
+            .map(|(i, line)| FreshRange::parse(i, line))
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+        let available_ids = numbered(&sections[1], ids_line)
+            .map(|(i, line)| {
+                let column = line.len() - line.trim_start().len() + 1;
+                line.trim()
+                    .parse()
+                    .map_err(|_| Error::parse(i, column, line.trim(), "an ingredient ID"))
+            })
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+
+        Ok(Self::new(fresh_ranges, available_ids))
+    }
+
+    fn is_fresh(&self, id: IngredientId) -> bool {
+        self.fresh.contains(id)
+    }
+}
+
+fn part1(db: &Database) -> usize {
+    db.available_ids
+        .iter()
+        .filter(|&&id| db.is_fresh(id))
+        .count()
+}
+
+fn part2(db: &Database) -> u64 {
+    db.fresh.len() as u64
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = Database;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_sections_split_str(input, Database::parse)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::aoc_test!(Day5, file: "examples/1.txt");
+
+    fn parse_test_input() -> Database {
+        Day5::parse(include_str!("../examples/1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let db = parse_test_input();
+        let fresh: IntervalSet<IngredientId> =
+            [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(db.fresh, fresh);
+        assert_eq!(db.available_ids, vec![1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_lines() {
+        let position = |input| match Day5::parse(input) {
+            Err(Error::Parse {
+                line, column, text, ..
+            }) => (line, column, text),
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!(position("3-5\n10\n\n1\n"), (2, 1, "10".to_string()));
+        assert_eq!(position("3-5\n10-x\n\n1\n"), (2, 4, "x".to_string()));
+        assert_eq!(position("3-5\n\n1\nabc\n"), (4, 1, "abc".to_string()));
+        assert!(matches!(Day5::parse("3-5\n"), Err(Error::Shape(_))));
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        let db = parse_test_input();
+        let merged: Vec<_> = db.fresh.iter().collect();
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0], 3..=5);
+        assert_eq!(merged[1], 10..=20);
+    }
+}
@@ -0,0 +1,328 @@
+use aoc_utils::{Error, Solution, parse_file_str};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Problem {
+    operation: char,
+    numbers: Vec<u64>,
+}
+
+impl Problem {
+    fn new(operation: char, numbers: Vec<u64>) -> Self {
+        Self { operation, numbers }
+    }
+
+    fn calculate(&self) -> u64 {
+        match self.operation {
+            '+' => self.numbers.iter().sum(),
+            '*' => self.numbers.iter().product(),
+            _ => 0,
+        }
+    }
+}
+
+// whitespace-separated words of `line`, with their 1-based columns
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = 0;
+    line.split(char::is_whitespace).filter_map(move |word| {
+        let column = start + 1;
+        start += word.len() + 1;
+        (!word.is_empty()).then_some((column, word))
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worksheet {
+    lines: Vec<String>,
+}
+
+impl Worksheet {
+    fn new(lines: Vec<String>) -> Self {
+        Self { lines }
+    }
+
+    fn solve(&self, right_to_left: bool) -> u64 {
+        self.parse_problems(right_to_left)
+            .iter()
+            .map(|p| p.calculate())
+            .sum()
+    }
+
+    /// Checks every number and operation up front, so solving never has to
+    /// skip anything.
+    fn parse(lines: Vec<String>) -> aoc_utils::Result<Self> {
+        let Some((operations, number_lines)) = lines.split_last() else {
+            return Err(Error::shape("worksheet is empty"));
+        };
+
+        let problems = words(operations).count();
+        for (column, word) in words(operations) {
+            if word != "+" && word != "*" {
+                return Err(Error::parse(lines.len(), column, word, "+ or *"));
+            }
+        }
+
+        for (i, line) in number_lines.iter().enumerate() {
+            let mut count = 0;
+            for (column, word) in words(line) {
+                word.parse::<u64>()
+                    .map_err(|_| Error::parse(i + 1, column, word, "a number"))?;
+                count += 1;
+            }
+            if count != problems {
+                return Err(Error::shape(format!(
+                    "line {} has {} numbers, but there are {} operations",
+                    i + 1,
+                    count,
+                    problems
+                )));
+            }
+        }
+
+        Ok(Self::new(lines))
+    }
+
+    fn parse_problems(&self, right_to_left: bool) -> Vec<Problem> {
+        if self.lines.is_empty() {
+            return Vec::new();
+        }
+
+        let number_lines = &self.lines[..self.lines.len() - 1];
+        let operations: Vec<char> = self.lines[self.lines.len() - 1]
+            .split_whitespace()
+            .filter_map(|s| s.chars().next())
+            .collect();
+
+        if right_to_left {
+            Self::parse_right_to_left(number_lines, &operations)
+        } else {
+            Self::parse_left_to_right(number_lines, &operations)
+        }
+    }
+
+    fn parse_left_to_right(number_lines: &[String], operations: &[char]) -> Vec<Problem> {
+        let split_lines: Vec<Vec<&str>> = number_lines
+            .iter()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+
+        if split_lines.is_empty() {
+            return Vec::new();
+        }
+
+        let num_problems = split_lines[0].len();
+        let mut problems: Vec<Problem> = Vec::new();
+
+        for col in 0..num_problems {
+            let numbers: Vec<u64> = split_lines
+                .iter()
+                .map(|row| {
+                    row[col]
+                        .parse()
+                        .expect("Worksheet::parse checks every number")
+                })
+                .collect();
+
+            if col < operations.len() {
+                problems.push(Problem::new(operations[col], numbers));
+            }
+        }
+
+        problems
+    }
+
+    fn parse_right_to_left(number_lines: &[String], operations: &[char]) -> Vec<Problem> {
+        let split_lines: Vec<Vec<String>> = number_lines
+            .iter()
+            .map(|line| line.split_whitespace().map(|s| s.to_string()).collect())
+            .collect();
+
+        if split_lines.is_empty() {
+            return Vec::new();
+        }
+
+        let num_cols = split_lines[0].len();
+        let mut widths: Vec<usize> = Vec::new();
+
+        for col in 0..num_cols {
+            let max_width = split_lines
+                .iter()
+                .filter_map(|row| row.get(col).map(|s| s.len()))
+                .max()
+                .unwrap_or(0);
+            widths.push(max_width);
+        }
+
+        let mut problems: Vec<Problem> = Vec::new();
+        let mut skip = 0;
+
+        for (width, &op) in widths.iter().zip(operations.iter()) {
+            let mut numbers: Vec<u64> = Vec::new();
+            let problem_chars: Vec<String> = number_lines
+                .iter()
+                .map(|line| {
+                    let end = (skip + width).min(line.len());
+                    let slice = if skip < line.len() {
+                        &line[skip..end]
+                    } else {
+                        ""
+                    };
+                    format!("{:width$}", slice, width = width)
+                })
+                .collect();
+
+            // transpose to get columns
+            for digit_pos in 0..*width {
+                let mut digit_str = String::new();
+                for row in &problem_chars {
+                    if let Some(ch) = row.chars().nth(digit_pos)
+                        && ch != ' '
+                    {
+                        digit_str.push(ch);
+                    }
+                }
+
+                if !digit_str.is_empty()
+                    && let Ok(num) = digit_str.parse::<u64>()
+                {
+                    numbers.push(num);
+                }
+            }
+
+            problems.push(Problem::new(op, numbers));
+            skip += width + 1; // +1 for space separator
+        }
+
+        problems
+    }
+}
+
+fn part1(ws: &Worksheet) -> u64 {
+    ws.solve(false)
+}
+
+fn part2(ws: &Worksheet) -> u64 {
+    ws.solve(true)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_file_str(input, Worksheet::parse)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::text::dedent;
+
+    aoc_utils::aoc_test!(Day6, file: "examples/1.txt");
+
+    fn parse_test_input() -> Worksheet {
+        Day6::parse(include_str!("../examples/1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let ws = parse_test_input();
+        assert_eq!(ws.lines.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_worksheet() {
+        let error = |input| Day6::parse(input).unwrap_err();
+
+        assert!(matches!(
+            error("12 3x\n4  5\n+  *\n"),
+            Error::Parse { line: 1, column: 4, ref text, .. } if text == "3x"
+        ));
+        assert!(matches!(
+            error("12 3\n4  5\n+  -\n"),
+            Error::Parse { line: 3, column: 4, ref text, .. } if text == "-"
+        ));
+        assert!(matches!(error("12 3\n4\n+  *\n"), Error::Shape(_)));
+        assert!(matches!(error(""), Error::Shape(_)));
+    }
+
+    #[test]
+    fn test_parse_left_to_right() {
+        let ws = parse_test_input();
+        let problems = ws.parse_problems(false);
+
+        assert_eq!(problems[0].operation, '*');
+        assert_eq!(problems[0].numbers, vec![123, 45, 6]);
+
+        assert_eq!(problems[1].operation, '+');
+        assert_eq!(problems[1].numbers, vec![328, 64, 98]);
+
+        assert_eq!(problems[2].operation, '*');
+        assert_eq!(problems[2].numbers, vec![51, 387, 215]);
+
+        assert_eq!(problems[3].operation, '+');
+        assert_eq!(problems[3].numbers, vec![64, 23, 314]);
+    }
+
+    #[test]
+    fn test_parse_right_to_left() {
+        let ws = parse_test_input();
+        let problems = ws.parse_problems(true);
+
+        assert_eq!(problems[0].operation, '*');
+        assert_eq!(problems[0].numbers, vec![1, 24, 356]);
+
+        assert_eq!(problems[1].operation, '+');
+        assert_eq!(problems[1].numbers, vec![369, 248, 8]);
+
+        assert_eq!(problems[2].operation, '*');
+        assert_eq!(problems[2].numbers, vec![32, 581, 175]);
+
+        assert_eq!(problems[3].operation, '+');
+        assert_eq!(problems[3].numbers, vec![623, 431, 4]);
+    }
+
+    #[test]
+    fn test_parse_indented() {
+        let ws = Day6::parse(&dedent(
+            "
+            123 328
+             45 64
+            *   +
+            ",
+        ))
+        .unwrap();
+        let problems = ws.parse_problems(false);
+        assert_eq!(problems[0].numbers, vec![123, 45]);
+        assert_eq!(problems[1].numbers, vec![328, 64]);
+        assert_eq!(part1(&ws), 123 * 45 + 328 + 64);
+    }
+
+    #[test]
+    fn test_calculate_addition() {
+        let problem = Problem::new('+', vec![1, 2, 3]);
+        let answer = problem.calculate();
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn test_calculate_multiplication() {
+        let problem = Problem::new('*', vec![4, 5, 6]);
+        let answer = problem.calculate();
+        assert_eq!(answer, 120);
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_utils::search::{bfs, count_paths_dag};
+use aoc_utils::solution::Implementations;
+use aoc_utils::{Error, FromGrid, Grid, Solution, parse_grid_from_str};
+use std::collections::HashMap;
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifold {
+    start: Coord,
+    grid: Grid<char>,
+}
+
+impl Manifold {
+    fn at(&self, row: usize, col: usize) -> Option<char> {
+        self.grid.get(row, col).copied()
+    }
+
+    fn start(&self) -> Coord {
+        self.start
+    }
+
+    fn is_bottom(&self, row: usize) -> bool {
+        row == self.grid.height() - 1
+    }
+
+    // beams go straight down, and a splitter sends them out to either side
+    fn beam_successors(&self, &(row, col): &Coord) -> Vec<Coord> {
+        if self.is_bottom(row) {
+            return Vec::new();
+        }
+
+        let next = match self.at(row, col) {
+            Some('^') => vec![(row, col.wrapping_sub(1)), (row, col + 1)],
+            _ => vec![(row + 1, col)],
+        };
+        next.into_iter()
+            .filter(|&(r, c)| self.grid.in_bounds(r, c))
+            .collect()
+    }
+
+    // a particle at a splitter moves down-left or down-right, otherwise down
+    fn timeline_successors(&self, &(row, col): &Coord) -> Vec<Coord> {
+        let next = match self.at(row, col) {
+            Some('^') => vec![(row + 1, col.wrapping_sub(1)), (row + 1, col + 1)],
+            _ => vec![(row + 1, col)],
+        };
+        next.into_iter()
+            .filter(|&(r, c)| self.grid.in_bounds(r, c))
+            .collect()
+    }
+}
+
+impl FromGrid for Manifold {
+    type Element = char;
+
+    fn parse_element(_row: usize, _col: usize, c: char) -> Self::Element {
+        c
+    }
+
+    fn from_grid(grid: Grid<Self::Element>) -> aoc_utils::Result<Self> {
+        let start = grid
+            .find(|&c| c == 'S')
+            .ok_or_else(|| Error::shape("no starting point 'S' found"))?;
+        Ok(Self { start, grid })
+    }
+}
+
+fn count_splits(manifold: &Manifold) -> u64 {
+    let reached = bfs(manifold.start(), |coord| manifold.beam_successors(coord));
+
+    reached
+        .dist
+        .keys()
+        .filter(|&&(row, col)| manifold.at(row, col) == Some('^') && !manifold.is_bottom(row))
+        .count() as u64
+}
+
+fn count_timelines(manifold: &Manifold) -> usize {
+    let counts = count_paths_dag(
+        manifold.start(),
+        |coord| manifold.timeline_successors(coord),
+        |&(row, _)| manifold.is_bottom(row),
+    );
+
+    counts.total as usize
+}
+
+// the same count by recursion, memoizing the timelines from each cell
+fn count_timelines_dfs(manifold: &Manifold) -> usize {
+    fn timelines(manifold: &Manifold, coord: Coord, memo: &mut HashMap<Coord, usize>) -> usize {
+        if manifold.is_bottom(coord.0) {
+            return 1;
+        }
+        if let Some(&count) = memo.get(&coord) {
+            return count;
+        }
+
+        let count = manifold
+            .timeline_successors(&coord)
+            .into_iter()
+            .map(|next| timelines(manifold, next, memo))
+            .sum();
+        memo.insert(coord, count);
+        count
+    }
+
+    timelines(manifold, manifold.start(), &mut HashMap::new())
+}
+
+fn part1(manifold: &Manifold) -> u64 {
+    count_splits(manifold)
+}
+
+fn part2(manifold: &Manifold) -> usize {
+    count_timelines(manifold)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_grid_from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+
+    fn part2_implementations() -> Implementations<Self::Input, Self::Answer2> {
+        vec![("memoized dfs", count_timelines_dfs)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::aoc_test!(Day7, file: "examples/1.txt");
+
+    fn parse_test_input() -> Manifold {
+        Day7::parse(include_str!("../examples/1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let manifold = parse_test_input();
+        assert_eq!(manifold.grid.height(), 16);
+        assert_eq!(manifold.grid.width(), 15);
+        assert_eq!(manifold.start, (0, 7));
+    }
+
+    #[test]
+    fn test_count_timelines_dfs() {
+        let manifold = parse_test_input();
+        assert_eq!(count_timelines_dfs(&manifold), count_timelines(&manifold));
+    }
+}
@@ -0,0 +1,169 @@
+use ::std::cmp::Ordering;
+use aoc_utils::dsu::DisjointSet;
+use aoc_utils::geom::Point3;
+use aoc_utils::{Solution, parse_lines_strict_str};
+use std::collections::BinaryHeap;
+
+type Point = Point3<u64>;
+
+fn parse_point(line: &str) -> Option<Point> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let parts: Vec<&str> = line.split(',').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let x: u64 = parts[0].parse().ok()?;
+    let y: u64 = parts[1].parse().ok()?;
+    let z: u64 = parts[2].parse().ok()?;
+
+    Some(Point::new(x, y, z))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Edge {
+    u: usize,
+    v: usize,
+    // squared distance orders edges the same as the real distance, without
+    // needing floats
+    weight: u64,
+}
+
+impl Edge {
+    fn new(u: usize, v: usize, points: &[Point]) -> Self {
+        let p1 = &points[u];
+        let p2 = &points[v];
+        let weight = p1.squared_euclidean(p2);
+        Self { u, v, weight }
+    }
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reverse for min-heap (BinaryHeap is max-heap by default)
+        other.weight.cmp(&self.weight)
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn generate_edges(points: &[Point]) -> BinaryHeap<Edge> {
+    let mut heap = BinaryHeap::new();
+
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let edge = Edge::new(i, j, points);
+            heap.push(edge);
+        }
+    }
+
+    heap
+}
+
+fn part1(points: &[Point], k: usize) -> usize {
+    let n = points.len();
+    let mut edges = generate_edges(points);
+    let mut uf = DisjointSet::new(n);
+
+    for _ in 0..k {
+        if edges.is_empty() {
+            break;
+        }
+
+        let edge = edges.pop().unwrap();
+        uf.union(edge.u, edge.v);
+    }
+
+    let mut comp_sizes = uf.component_sizes();
+    comp_sizes.sort_by(|a, b| b.cmp(a));
+
+    comp_sizes.iter().take(3).product()
+}
+
+fn part2(points: &[Point]) -> usize {
+    let mut edges = generate_edges(points);
+    let mut uf = DisjointSet::new(points.len());
+
+    while let Some(edge) = edges.pop() {
+        if uf.union(edge.u, edge.v) && uf.component_count() == 1 {
+            let u = points[edge.u];
+            let v = points[edge.v];
+            return (u.x as usize) * (v.x as usize);
+        }
+    }
+    0
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {
+        parse_lines_strict_str(input, "a point like 162,817,812", parse_point)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input, 1000)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::examples::Example;
+
+    aoc_utils::aoc_test!(Day8, file: "examples/1.txt", part2: 25272);
+
+    fn example() -> Example {
+        Example::load(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/1.txt")).unwrap()
+    }
+
+    fn parse_test_input() -> Vec<Point> {
+        Day8::parse(&example().input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let p = parse_test_input();
+        assert_eq!(p.len(), 20);
+        assert_eq!(p[0], Point::new(162, 817, 812));
+        assert_eq!(p[2], Point::new(906, 360, 560));
+        assert_eq!(p[12], Point::new(117, 168, 530));
+        assert_eq!(p[19], Point::new(425, 690, 689));
+    }
+
+    #[test]
+    fn test_edge() {
+        let u = Point::new(1, 2, 3);
+        let v = Point::new(4, 5, 6);
+        let points = vec![u, v];
+        let e = Edge::new(0, 1, &points);
+        assert_eq!(e.u, 0);
+        assert_eq!(e.v, 1);
+        assert_eq!(e.weight, 27);
+    }
+
+    #[test]
+    fn test_part1() {
+        let points = parse_test_input();
+        assert_eq!(Some(part1(&points, 10)), example().expected(1));
+    }
+}
@@ -0,0 +1,630 @@
+mod registry;
+mod scaffold;
+
+use aoc_utils::SolutionEntry;
+use aoc_utils::bench::{self, AlternativeTiming, DayTimings, History, Timing};
+use aoc_utils::examples;
+use aoc_utils::fetch::Fetcher;
+use aoc_utils::input::resolve_input;
+use aoc_utils::solution;
+use aoc_utils::submit::{Ledger, Outcome, Submitter, Verdict};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage:
+    aoc run <year> <day> [--part <1|2>] [--input <path>]
+    aoc run --all [--year <year>] [--part <1|2>]
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <1|2>
+    aoc bench (<year> <day> | --all [--year <year>]) [--runs <n>]
+              [--threshold <percent>] [--history <path>] [--no-save]
+    aoc examples <year> <day> <puzzle.html>
+    aoc new <year> <day>
+    aoc list [--year <year>]
+    aoc summary [--year <year>]
+
+Inputs default to YYYY/dayN/input.txt under the workspace root, then
+$AOC_INPUT_DIR_YYYY/dayN/input.txt or $AOC_INPUT_DIR_YYYY/dayN.txt, then
+$AOC_INPUT_DIR/YYYY/dayN/input.txt or $AOC_INPUT_DIR/YYYY/dayN.txt, then
+input previously downloaded with `aoc fetch`.
+
+`aoc bench` compares against the last run recorded in bench_history.jsonl
+and fails if any phase got more than --threshold percent (default 20)
+slower.";
+
+const DEFAULT_RUNS: usize = 20;
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+fn workspace_root() -> &'static Path {
+    // this crate lives one level below the workspace root
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate is inside the workspace")
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct RunArgs {
+    all: bool,
+    /// With `all`, only this year's days.
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+}
+
+fn parse_number<T: std::str::FromStr>(what: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing {}", what))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid {}: {:?}", what, value))
+}
+
+fn parse_run_args<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs, String> {
+    let mut run = RunArgs::default();
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => run.all = true,
+            "--year" => run.year = Some(parse_number("year", args.next())?),
+            "--part" => {
+                let part = parse_number("part", args.next())?;
+                if part != 1 && part != 2 {
+                    return Err(format!("part must be 1 or 2, got {}", part));
+                }
+                run.part = Some(part);
+            }
+            "--input" => {
+                let path = args.next().ok_or("missing input path")?;
+                run.input = Some(PathBuf::from(path));
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {}", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    if !run.all {
+        if run.year.is_some() {
+            return Err("--year only applies to --all".to_string());
+        }
+        run.year = Some(parse_number("year", positional.next())?);
+        run.day = Some(parse_number("day", positional.next())?);
+    }
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+    if run.all && run.input.is_some() {
+        return Err("--input can't be combined with --all".to_string());
+    }
+
+    Ok(run)
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchArgs {
+    all: bool,
+    /// With `all`, only this year's days.
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: usize,
+    threshold: f64,
+    history: PathBuf,
+    save: bool,
+}
+
+fn parse_bench_args<I: Iterator<Item = String>>(mut args: I) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs {
+        all: false,
+        year: None,
+        day: None,
+        runs: DEFAULT_RUNS,
+        threshold: DEFAULT_THRESHOLD,
+        history: workspace_root().join("bench_history.jsonl"),
+        save: true,
+    };
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => bench.all = true,
+            "--year" => bench.year = Some(parse_number("year", args.next())?),
+            "--runs" => {
+                bench.runs = parse_number("run count", args.next())?;
+                if bench.runs == 0 {
+                    return Err("run count must be at least 1".to_string());
+                }
+            }
+            "--threshold" => bench.threshold = parse_number("threshold", args.next())?,
+            "--history" => {
+                let path = args.next().ok_or("missing history path")?;
+                bench.history = PathBuf::from(path);
+            }
+            "--no-save" => bench.save = false,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {}", flag)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    if !bench.all {
+        if bench.year.is_some() {
+            return Err("--year only applies to --all".to_string());
+        }
+        bench.year = Some(parse_number("year", positional.next())?);
+        bench.day = Some(parse_number("day", positional.next())?);
+    }
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+
+    Ok(bench)
+}
+
+// the optional `--year <year>` of list and summary
+fn parse_year_filter<I: Iterator<Item = String>>(mut args: I) -> Result<Option<u16>, String> {
+    let year = match args.next().as_deref() {
+        None => return Ok(None),
+        Some("--year") => parse_number("year", args.next())?,
+        Some(other) => return Err(format!("unexpected argument {:?}", other)),
+    };
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+    Ok(Some(year))
+}
+
+// every registered day, or one year's
+fn entries_for(year: Option<u16>) -> Result<Vec<SolutionEntry>, String> {
+    let entries = registry::in_year(year);
+    match (year, entries.is_empty()) {
+        (Some(year), true) => Err(format!("no solutions for {}", year)),
+        _ => Ok(entries),
+    }
+}
+
+// explicit --input wins, otherwise look where the day binary itself would
+fn find_input(entry: &SolutionEntry, explicit: Option<PathBuf>) -> aoc_utils::Result<PathBuf> {
+    if let Some(path) = explicit {
+        return Ok(path);
+    }
+
+    let default = workspace_root().join(entry.input_path());
+    resolve_input(entry.year, entry.day, default.parent(), None)
+        .or_else(|err| Fetcher::from_env().cached(entry.year, entry.day).ok_or(err))
+}
+
+fn run_entry(entry: &SolutionEntry, input: &Path, part: Option<u8>) -> aoc_utils::Result<()> {
+    let parsed = entry.parse(&fs::read_to_string(input)?)?;
+
+    for p in [1, 2] {
+        if part.is_none_or(|part| part == p) {
+            println!("Part {}: {}", p, entry.part(p, &parsed));
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if args.all {
+        let mut failed = false;
+        for entry in entries_for(args.year)? {
+            println!("{} day {}", entry.year, entry.day);
+            let result =
+                find_input(&entry, None).and_then(|input| run_entry(&entry, &input, args.part));
+            if let Err(err) = result {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+        return if failed {
+            Err("some days failed".to_string())
+        } else {
+            Ok(())
+        };
+    }
+
+    // both are set whenever --all isn't
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let entry =
+        registry::find(year, day).ok_or_else(|| format!("no solution for {} day {}", year, day))?;
+    let input = find_input(&entry, args.input).map_err(|err| err.to_string())?;
+
+    run_entry(&entry, &input, args.part).map_err(|err| format!("{}: {}", input.display(), err))
+}
+
+fn format_timing(timing: &Timing) -> String {
+    format!(
+        "median {:>10.3?}  min {:>10.3?}  spread {:>10.3?}",
+        timing.median,
+        timing.min,
+        timing.spread()
+    )
+}
+
+// prints one day's timings, then each alternative implementation under the
+// part it solves, and returns whether any phase regressed
+fn report(
+    timings: &DayTimings,
+    alternatives: &[AlternativeTiming],
+    previous: Option<&DayTimings>,
+    threshold: f64,
+) -> bool {
+    let regressions = previous
+        .map(|previous| bench::regressions(previous, timings, threshold / 100.0))
+        .unwrap_or_default();
+
+    println!(
+        "{} day {} ({} runs)",
+        timings.year, timings.day, timings.runs
+    );
+    let name_width = alternatives.iter().map(|a| a.name.len()).max().unwrap_or(0);
+    for (phase, timing) in timings.phases() {
+        let flag = regressions
+            .iter()
+            .find(|regression| regression.phase == phase)
+            .map(|regression| {
+                format!(
+                    "  REGRESSED +{:.0}% (was {:.3?})",
+                    regression.slowdown() * 100.0,
+                    regression.before
+                )
+            })
+            .unwrap_or_default();
+        println!("  {}  {}{}", phase, format_timing(timing), flag);
+        for alternative in alternatives
+            .iter()
+            .filter(|alternative| phase == format!("part{}", alternative.part))
+        {
+            println!(
+                "    {:<name_width$}  {}",
+                alternative.name,
+                format_timing(&alternative.timing)
+            );
+        }
+    }
+
+    !regressions.is_empty()
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let entries = if args.all {
+        entries_for(args.year)?
+    } else {
+        // both are set whenever --all isn't
+        let (year, day) = (args.year.unwrap(), args.day.unwrap());
+        vec![
+            registry::find(year, day)
+                .ok_or_else(|| format!("no solution for {} day {}", year, day))?,
+        ]
+    };
+    let mut history = History::open(&args.history)
+        .map_err(|err| format!("{}: {}", args.history.display(), err))?;
+
+    let mut failed = false;
+    let mut regressed = false;
+    for entry in entries {
+        let timings = find_input(&entry, None)
+            .and_then(|input| Ok(fs::read_to_string(input)?))
+            .and_then(|text| {
+                Ok((
+                    bench::bench_entry(&entry, &text, args.runs)?,
+                    bench::bench_alternatives(&entry, &text, args.runs)?,
+                ))
+            });
+        let (timings, alternatives) = match timings {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("{} day {}: {}", entry.year, entry.day, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        regressed |= report(
+            &timings,
+            &alternatives,
+            history.last(entry.year, entry.day),
+            args.threshold,
+        );
+        if args.save {
+            history.record(timings).map_err(|err| err.to_string())?;
+        }
+    }
+
+    match (failed, regressed) {
+        (true, _) => Err("some days failed".to_string()),
+        (_, true) => Err("some days regressed".to_string()),
+        _ => Ok(()),
+    }
+}
+
+fn fetch<I: Iterator<Item = String>>(mut args: I) -> Result<(), String> {
+    let year: u16 = parse_number("year", args.next())?;
+    let day: u8 = parse_number("day", args.next())?;
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+
+    let path = Fetcher::from_env()
+        .fetch(year, day)
+        .map_err(|err| err.to_string())?;
+    println!("{}", path.display());
+    Ok(())
+}
+
+fn submit<I: Iterator<Item = String>>(mut args: I) -> Result<(), String> {
+    let year: u16 = parse_number("year", args.next())?;
+    let day: u8 = parse_number("day", args.next())?;
+    let part: u8 = parse_number("part", args.next())?;
+    if part != 1 && part != 2 {
+        return Err(format!("part must be 1 or 2, got {}", part));
+    }
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+
+    let entry =
+        registry::find(year, day).ok_or_else(|| format!("no solution for {} day {}", year, day))?;
+    let input = find_input(&entry, None).map_err(|err| err.to_string())?;
+    let parsed = fs::read_to_string(&input)
+        .map_err(aoc_utils::Error::from)
+        .and_then(|text| entry.parse(&text))
+        .map_err(|err| format!("{}: {}", input.display(), err))?;
+    // same check as the day binary: never post an answer the alternatives
+    // dispute
+    let checked = entry.cross_check(part, &parsed);
+    if !solution::report(part, &checked) {
+        return Err(aoc_utils::Error::Disagreement { part }.to_string());
+    }
+    let answer = &checked[0].answer;
+
+    let mut submitter = Submitter::from_env().map_err(|err| err.to_string())?;
+    match submitter
+        .submit(year, day, part, answer)
+        .map_err(|err| err.to_string())?
+    {
+        Outcome::Refused(refusal) => Err(format!("not submitted: {}", refusal)),
+        Outcome::Submitted(Verdict::Right) => {
+            println!("{}", Verdict::Right);
+            Ok(())
+        }
+        Outcome::Submitted(verdict) => Err(verdict.to_string()),
+    }
+}
+
+fn examples<I: Iterator<Item = String>>(mut args: I) -> Result<(), String> {
+    let year: u16 = parse_number("year", args.next())?;
+    let day: u8 = parse_number("day", args.next())?;
+    let page = args.next().ok_or("missing puzzle page")?;
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+
+    let html = fs::read_to_string(&page).map_err(|err| format!("{}: {}", page, err))?;
+    let extracted = examples::extract(&html);
+    if extracted.blocks.is_empty() {
+        return Err(format!("{}: no <pre><code> blocks found", page));
+    }
+
+    let dir = workspace_root().join(format!("{}/day{}/examples", year, day));
+    for path in extracted.write(&dir).map_err(|err| err.to_string())? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn new<I: Iterator<Item = String>>(mut args: I) -> Result<(), String> {
+    let year: u16 = parse_number("year", args.next())?;
+    let day: u8 = parse_number("day", args.next())?;
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument {:?}", extra));
+    }
+    if year < 2015 || !(1..=25).contains(&day) {
+        return Err(format!("no puzzle for {} day {}", year, day));
+    }
+
+    let scaffold = scaffold::create(workspace_root(), year, day).map_err(|err| err.to_string())?;
+    for path in &scaffold.files {
+        println!("{}", path.display());
+    }
+    match &scaffold.added_member {
+        Some(member) => println!("added {:?} to the workspace members", member),
+        None => println!("already covered by the workspace members"),
+    }
+    println!(
+        "put the input at {} or run `aoc fetch {} {}`",
+        scaffold.dir.join("input.txt").display(),
+        year,
+        day
+    );
+    let name = scaffold::crate_name(year, day);
+    println!(
+        "to use it from `aoc`, add {name} to aoc/Cargo.toml and \
+         SolutionEntry::of::<{name}::Day{day}>() to aoc/src/registry.rs"
+    );
+    Ok(())
+}
+
+fn list(year: Option<u16>) -> Result<(), String> {
+    for entry in entries_for(year)? {
+        println!("{} day {}", entry.year, entry.day);
+    }
+    Ok(())
+}
+
+// one row per year and a column per day: `**`/`* ` for accepted answers,
+// `o ` for a solution without any, `. ` for no solution
+fn summary_table<F: Fn(u16, u8) -> usize>(entries: &[SolutionEntry], stars: F) -> String {
+    let mut years: Vec<u16> = entries.iter().map(|entry| entry.year).collect();
+    years.dedup();
+
+    let mut table = String::from("year ");
+    for day in 1..=25 {
+        table += &format!(" {:>2}", day);
+    }
+    table += "  days  stars\n";
+
+    for year in years {
+        let (mut days, mut total) = (0, 0);
+        table += &format!("{} ", year);
+        for day in 1..=25 {
+            let solved = entries.iter().any(|e| e.year == year && e.day == day);
+            let cell = match (solved, stars(year, day)) {
+                (_, 2) => "**",
+                (_, 1) => "* ",
+                (true, _) => "o ",
+                (false, _) => ". ",
+            };
+            days += usize::from(solved);
+            total += stars(year, day);
+            table += &format!(" {}", cell);
+        }
+        table += &format!("  {:>4}  {:>5}\n", days, total);
+    }
+
+    table
+}
+
+fn summary(year: Option<u16>) -> Result<(), String> {
+    let entries = entries_for(year)?;
+    let ledger = Ledger::open(Ledger::default_path()).map_err(|err| err.to_string())?;
+    print!(
+        "{}",
+        summary_table(&entries, |year, day| ledger.stars(year, day))
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => parse_run_args(args).and_then(run),
+        Some("fetch") => fetch(args),
+        Some("submit") => submit(args),
+        Some("bench") => parse_bench_args(args).and_then(bench),
+        Some("examples") => examples(args),
+        Some("new") => new(args),
+        Some("list") => parse_year_filter(args).and_then(list),
+        Some("summary") => parse_year_filter(args).and_then(summary),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> impl Iterator<Item = String> {
+        line.split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_run_args() {
+        let run = parse_run_args(args("2025 5 --part 2 --input path/to/input.txt")).unwrap();
+        assert_eq!(
+            run,
+            RunArgs {
+                all: false,
+                year: Some(2025),
+                day: Some(5),
+                part: Some(2),
+                input: Some(PathBuf::from("path/to/input.txt")),
+            }
+        );
+
+        let run = parse_run_args(args("--all")).unwrap();
+        assert!(run.all);
+        assert_eq!(run.year, None);
+
+        let run = parse_run_args(args("--all --year 2024")).unwrap();
+        assert_eq!((run.all, run.year), (true, Some(2024)));
+    }
+
+    #[test]
+    fn test_parse_run_args_errors() {
+        assert!(parse_run_args(args("2025")).is_err());
+        assert!(parse_run_args(args("2025 5 --part 3")).is_err());
+        assert!(parse_run_args(args("2025 5 6")).is_err());
+        assert!(parse_run_args(args("--all --input x")).is_err());
+        assert!(parse_run_args(args("2025 5 --verbose")).is_err());
+        assert!(parse_run_args(args("--year 2024 2025 5")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let bench = parse_bench_args(args("2025 3")).unwrap();
+        assert_eq!((bench.year, bench.day), (Some(2025), Some(3)));
+        assert_eq!(bench.runs, DEFAULT_RUNS);
+        assert_eq!(bench.history, workspace_root().join("bench_history.jsonl"));
+        assert!(bench.save);
+
+        let bench = parse_bench_args(args(
+            "--all --runs 5 --threshold 50 --history h.jsonl --no-save",
+        ))
+        .unwrap();
+        assert!(bench.all && !bench.save);
+        assert_eq!(bench.runs, 5);
+        assert_eq!(bench.threshold, 50.0);
+        assert_eq!(bench.history, PathBuf::from("h.jsonl"));
+
+        assert!(parse_bench_args(args("2025")).is_err());
+        assert!(parse_bench_args(args("2025 3 --runs 0")).is_err());
+    }
+
+    #[test]
+    fn test_parse_year_filter() {
+        assert_eq!(parse_year_filter(args("")), Ok(None));
+        assert_eq!(parse_year_filter(args("--year 2024")), Ok(Some(2024)));
+        assert!(parse_year_filter(args("2024")).is_err());
+        assert!(parse_year_filter(args("--year 2024 extra")).is_err());
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let entries = registry::in_year(Some(2025));
+        let table = summary_table(&entries[..3], |_, day| match day {
+            1 => 2,
+            2 => 1,
+            _ => 0,
+        });
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("year   1  2  3  4"));
+        assert!(lines[0].ends_with(" 25  days  stars"));
+        assert!(lines[1].starts_with("2025  ** *  o  .  . "));
+        assert!(lines[1].ends_with("     3      3"));
+    }
+
+    #[test]
+    fn test_registry_has_every_day() {
+        let days: Vec<_> = registry::all().iter().map(|e| (e.year, e.day)).collect();
+        assert_eq!(days, (1..=8).map(|day| (2025, day)).collect::<Vec<_>>());
+        assert!(registry::find(2025, 5).is_some());
+        assert!(registry::find(2025, 25).is_none());
+        assert_eq!(registry::in_year(Some(2025)).len(), 8);
+        assert!(registry::in_year(Some(2015)).is_empty());
+    }
+
+    #[test]
+    fn test_workspace_root() {
+        assert!(workspace_root().join("Cargo.toml").is_file());
+        assert!(workspace_root().join("aoc_utils").is_dir());
+    }
+}
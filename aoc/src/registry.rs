@@ -0,0 +1,29 @@
+use aoc_utils::SolutionEntry;
+
+/// Every solved day, ordered by year and then day.
+pub fn all() -> Vec<SolutionEntry> {
+    vec![
+        SolutionEntry::of::<day1_2025::Day1>(),
+        SolutionEntry::of::<day2_2025::Day2>(),
+        SolutionEntry::of::<day3_2025::Day3>(),
+        SolutionEntry::of::<day4_2025::Day4>(),
+        SolutionEntry::of::<day5_2025::Day5>(),
+        SolutionEntry::of::<day6_2025::Day6>(),
+        SolutionEntry::of::<day7_2025::Day7>(),
+        SolutionEntry::of::<day8_2025::Day8>(),
+    ]
+}
+
+pub fn find(year: u16, day: u8) -> Option<SolutionEntry> {
+    all()
+        .into_iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+/// Every day of `year`, or of every year.
+pub fn in_year(year: Option<u16>) -> Vec<SolutionEntry> {
+    all()
+        .into_iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .collect()
+}
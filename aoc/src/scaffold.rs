@@ -0,0 +1,242 @@
+use aoc_utils::{Error, Result};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What `aoc new` created.
+#[derive(Debug)]
+pub struct Scaffold {
+    pub dir: PathBuf,
+    pub files: Vec<PathBuf>,
+    /// The member glob added to the root `Cargo.toml`, if one was needed.
+    pub added_member: Option<String>,
+}
+
+/// Cargo needs package names to be unique across the workspace, so every
+/// day carries its year: `2025/day3` is `day3_2025`.
+pub fn crate_name(year: u16, day: u8) -> String {
+    format!("day{}_{}", day, year)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc_utils = {{ path = "../../aoc_utils/" }}
+"#
+    )
+}
+
+fn main_rs(name: &str, day: u8) -> String {
+    format!("aoc_utils::main!({name}::Day{day});\n")
+}
+
+fn lib_rs(year: u16, day: u8) -> String {
+    format!(
+        r#"use aoc_utils::{{Solution, read_lines_str}};
+
+fn part1(lines: &[String]) -> usize {{
+    // placeholder until the puzzle is solved
+    lines.len()
+}}
+
+fn part2(lines: &[String]) -> usize {{
+    // placeholder until the puzzle is solved
+    lines.len()
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> aoc_utils::Result<Self::Input> {{
+        Ok(read_lines_str(input))
+    }}
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {{
+        part1(input)
+    }}
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {{
+        part2(input)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    // fill in examples/1.txt and its answers in examples/1.expected, or
+    // extract them from the puzzle page with `aoc examples {year} {day}`
+    aoc_utils::aoc_test!(Day{day}, file: "examples/1.txt");
+}}
+"#
+    )
+}
+
+// only the plain and trailing-`*` patterns the root manifest uses
+fn member_matches(pattern: &str, path: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => path.starts_with(prefix) && !path[prefix.len()..].contains('/'),
+        None => pattern == path,
+    }
+}
+
+/// Adds `"YYYY/day*"` to the workspace members in `manifest`, unless some
+/// existing member already covers `YYYY/dayN`. Returns the new manifest
+/// text and the added member.
+fn with_member(manifest: &str, year: u16, day: u8) -> Result<(String, Option<String>)> {
+    let shape = || Error::shape("no `members = [...]` list in the root Cargo.toml");
+    let start = manifest.find("members = [").ok_or_else(shape)?;
+    let end = start + manifest[start..].find(']').ok_or_else(shape)?;
+
+    let path = format!("{}/day{}", year, day);
+    let covered = manifest[start..end]
+        .split(['[', ',', '\n'])
+        .map(|member| member.trim().trim_matches('"'))
+        .any(|member| member_matches(member, &path));
+    if covered {
+        return Ok((manifest.to_string(), None));
+    }
+
+    let member = format!("{}/day*", year);
+    // the closing bracket sits on its own line after the last member
+    let line_start = manifest[..end].rfind('\n').map_or(end, |i| i + 1);
+    let updated = format!(
+        "{}    \"{}\",\n{}",
+        &manifest[..line_start],
+        member,
+        &manifest[line_start..]
+    );
+    Ok((updated, Some(member)))
+}
+
+/// Creates the crate for `year` and `day` under `root` and makes sure the
+/// workspace picks it up. Fails without touching anything if the crate
+/// directory already exists.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<Scaffold> {
+    let dir = root.join(format!("{}/day{}", year, day));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        )
+        .into());
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let (manifest, added_member) = with_member(&fs::read_to_string(&manifest_path)?, year, day)?;
+
+    let name = crate_name(year, day);
+    let files = [
+        ("Cargo.toml", cargo_toml(&name)),
+        ("src/main.rs", main_rs(&name, day)),
+        ("src/lib.rs", lib_rs(year, day)),
+        ("examples/1.txt", String::new()),
+        ("examples/1.expected", "part1: 0\npart2: 0\n".to_string()),
+    ];
+    let mut written = Vec::new();
+    for (name, contents) in files {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().expect("every file is inside the crate"))?;
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    if added_member.is_some() {
+        fs::write(&manifest_path, manifest)?;
+    }
+
+    Ok(Scaffold {
+        dir,
+        files: written,
+        added_member,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc_utils\",\n    \"2025/day*\",\n]\n\nresolver = \"2\"\n";
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("Cargo.toml"), MANIFEST).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!(crate_name(2025, 3), "day3_2025");
+        assert_eq!(crate_name(2024, 3), "day3_2024");
+    }
+
+    #[test]
+    fn test_member_matches() {
+        assert!(member_matches("2025/day*", "2025/day9"));
+        assert!(member_matches("2025/day9", "2025/day9"));
+        assert!(!member_matches("2025/day*", "2024/day9"));
+        assert!(!member_matches("2025/day*", "2025/day9/nested"));
+    }
+
+    #[test]
+    fn test_with_member() {
+        let (manifest, added) = with_member(MANIFEST, 2025, 9).unwrap();
+        assert_eq!((manifest.as_str(), added), (MANIFEST, None));
+
+        let (manifest, added) = with_member(MANIFEST, 2024, 1).unwrap();
+        assert_eq!(added.as_deref(), Some("2024/day*"));
+        assert!(manifest.contains("    \"2025/day*\",\n    \"2024/day*\",\n]\n"));
+
+        assert!(with_member("[package]\n", 2024, 1).is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = TempDir::new("create");
+
+        let scaffold = create(&root.0, 2024, 3).unwrap();
+        assert_eq!(scaffold.dir, root.0.join("2024/day3"));
+        assert_eq!(scaffold.added_member.as_deref(), Some("2024/day*"));
+        assert_eq!(scaffold.files.len(), 5);
+
+        let lib = fs::read_to_string(scaffold.dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day3 {"));
+        assert!(lib.contains("const YEAR: u16 = 2024;"));
+        let main = fs::read_to_string(scaffold.dir.join("src/main.rs")).unwrap();
+        assert_eq!(main, "aoc_utils::main!(day3_2024::Day3);\n");
+        let manifest = fs::read_to_string(scaffold.dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day3_2024\""));
+        let manifest = fs::read_to_string(root.0.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("\"2024/day*\""));
+
+        // the year is covered now, and the crate can't be created twice
+        let scaffold = create(&root.0, 2024, 4).unwrap();
+        assert_eq!(scaffold.added_member, None);
+        assert!(create(&root.0, 2024, 4).is_err());
+    }
+}
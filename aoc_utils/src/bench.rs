@@ -0,0 +1,334 @@
+use crate::json::{self, Value};
+use crate::{Error, Result, SolutionEntry};
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Summary of repeated runs of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    pub fn spread(&self) -> Duration {
+        self.max - self.min
+    }
+}
+
+/// Times `runs` calls of `f`, after one untimed warm-up call.
+pub fn time<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Timing {
+    black_box(f());
+
+    let mut samples: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Timing::from_samples(&mut samples)
+}
+
+/// Timings for one day, one per phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+}
+
+impl DayTimings {
+    pub fn phases(&self) -> [(&'static str, &Timing); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Times parsing `input` and each part separately, `runs` times each.
+pub fn bench_entry(entry: &SolutionEntry, input: &str, runs: usize) -> Result<DayTimings> {
+    // fail early rather than timing an error path
+    let parsed = entry.parse(input)?;
+
+    Ok(DayTimings {
+        year: entry.year,
+        day: entry.day,
+        runs,
+        parse: time(runs, || entry.parse(input)),
+        part1: time(runs, || entry.part1(&parsed)),
+        part2: time(runs, || entry.part2(&parsed)),
+    })
+}
+
+/// One registered alternative implementation of a part, timed like the
+/// phases of [`DayTimings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternativeTiming {
+    pub part: u8,
+    pub name: &'static str,
+    pub timing: Timing,
+}
+
+/// Times every alternative in the day's `part*_implementations`, `runs`
+/// times each, so they can be compared with the default `part1` and
+/// `part2`.
+pub fn bench_alternatives(
+    entry: &SolutionEntry,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<AlternativeTiming>> {
+    let parsed = entry.parse(input)?;
+
+    Ok([1, 2]
+        .into_iter()
+        .flat_map(|part| {
+            entry
+                .time_alternatives(part, runs, &parsed)
+                .into_iter()
+                .map(move |(name, timing)| AlternativeTiming { part, name, timing })
+        })
+        .collect())
+}
+
+/// A phase whose median got slower than the threshold allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub phase: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// How much slower, e.g. 0.25 for 25%.
+    pub fn slowdown(&self) -> f64 {
+        self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0
+    }
+}
+
+/// Phases of `current` whose median is more than `threshold` (0.2 for 20%)
+/// slower than in `previous`.
+pub fn regressions(previous: &DayTimings, current: &DayTimings, threshold: f64) -> Vec<Regression> {
+    previous
+        .phases()
+        .into_iter()
+        .zip(current.phases())
+        .filter_map(|((phase, before), (_, after))| {
+            let regression = Regression {
+                phase,
+                before: before.median,
+                after: after.median,
+            };
+            (!before.median.is_zero() && regression.slowdown() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+/// Earlier benchmark runs, stored as one JSON object per line.
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    runs: Vec<DayTimings>,
+}
+
+impl History {
+    /// Loads the history at `path`. A missing file is an empty history.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let runs = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                json::parse(line)
+                    .as_ref()
+                    .and_then(timings_from_json)
+                    .ok_or_else(|| Error::parse(i + 1, 1, line, "a benchmark record"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { path, runs })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The most recent run for `year` and `day`.
+    pub fn last(&self, year: u16, day: u8) -> Option<&DayTimings> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| run.year == year && run.day == day)
+    }
+
+    /// Appends `timings` to the file and to the in-memory list.
+    pub fn record(&mut self, timings: DayTimings) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", timings_to_json(&timings))?;
+
+        self.runs.push(timings);
+        Ok(())
+    }
+}
+
+fn timing_to_json(timing: &Timing) -> Value {
+    json::object([
+        ("median_ns", Value::from(timing.median.as_nanos() as u64)),
+        ("min_ns", Value::from(timing.min.as_nanos() as u64)),
+        ("max_ns", Value::from(timing.max.as_nanos() as u64)),
+    ])
+}
+
+fn timing_from_json(value: &Value) -> Option<Timing> {
+    let nanos = |key| value.get(key)?.as_u64().map(Duration::from_nanos);
+    Some(Timing {
+        median: nanos("median_ns")?,
+        min: nanos("min_ns")?,
+        max: nanos("max_ns")?,
+    })
+}
+
+fn timings_to_json(timings: &DayTimings) -> Value {
+    let at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    json::object([
+        ("at", Value::from(at)),
+        ("year", Value::from(timings.year as u64)),
+        ("day", Value::from(timings.day as u64)),
+        ("runs", Value::from(timings.runs as u64)),
+        ("parse", timing_to_json(&timings.parse)),
+        ("part1", timing_to_json(&timings.part1)),
+        ("part2", timing_to_json(&timings.part2)),
+    ])
+}
+
+fn timings_from_json(value: &Value) -> Option<DayTimings> {
+    Some(DayTimings {
+        year: value.get("year")?.as_u64()?.try_into().ok()?,
+        day: value.get("day")?.as_u64()?.try_into().ok()?,
+        runs: value.get("runs")?.as_u64()? as usize,
+        parse: timing_from_json(value.get("parse")?)?,
+        part1: timing_from_json(value.get("part1")?)?,
+        part2: timing_from_json(value.get("part2")?)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn timing(median: u64) -> Timing {
+        Timing {
+            median: ms(median),
+            min: ms(median - 1),
+            max: ms(median + 2),
+        }
+    }
+
+    fn day(parse: u64, part1: u64, part2: u64) -> DayTimings {
+        DayTimings {
+            year: 2025,
+            day: 3,
+            runs: 10,
+            parse: timing(parse),
+            part1: timing(part1),
+            part2: timing(part2),
+        }
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let timing = Timing::from_samples(&mut [ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            (timing.median, timing.min, timing.max),
+            (ms(3), ms(1), ms(5))
+        );
+        assert_eq!(timing.spread(), ms(4));
+
+        let timing = Timing::from_samples(&mut [ms(4), ms(1), ms(2), ms(10)]);
+        assert_eq!(timing.median, ms(3));
+    }
+
+    #[test]
+    fn test_time_runs_closure() {
+        let mut calls = 0;
+        time(5, || calls += 1);
+        assert_eq!(calls, 6, "five timed runs plus a warm-up");
+    }
+
+    #[test]
+    fn test_regressions() {
+        let before = day(10, 10, 10);
+        let after = day(11, 13, 9);
+
+        let found = regressions(&before, &after, 0.2);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].phase, "part1");
+        assert!((found[0].slowdown() - 0.3).abs() < 1e-9);
+
+        assert_eq!(regressions(&before, &after, 0.05).len(), 2);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let path = env::temp_dir().join(format!("aoc_bench_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::open(&path).unwrap();
+        assert_eq!(history.last(2025, 3), None);
+        history.record(day(10, 20, 30)).unwrap();
+        history.record(day(11, 21, 31)).unwrap();
+
+        let reopened = History::open(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(reopened.unwrap().last(2025, 3), Some(&day(11, 21, 31)));
+    }
+}
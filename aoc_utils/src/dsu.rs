@@ -0,0 +1,585 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Union-find over the elements `0..n`, with union by size and path
+/// compression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new singleton element and returns its index.
+    pub fn push(&mut self) -> usize {
+        let index = self.parent.len();
+        self.parent.push(index);
+        self.size.push(1);
+        self.components += 1;
+        index
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+
+        // point everything on the path straight at the root
+        let mut curr = x;
+        while self.parent[curr] != root {
+            let next = self.parent[curr];
+            self.parent[curr] = root;
+            curr = next;
+        }
+
+        root
+    }
+
+    // like find, but without compressing, so it only needs &self
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `u` and `v`. Returns `false` if they were
+    /// already in the same set.
+    pub fn union(&mut self, u: usize, v: usize) -> bool {
+        let root_u = self.find(u);
+        let root_v = self.find(v);
+
+        // in the same set, no merge needed
+        if root_u == root_v {
+            return false;
+        }
+
+        // union by size. attach smaller tree to larger tree
+        let (small, large) = if self.size[root_u] < self.size[root_v] {
+            (root_u, root_v)
+        } else {
+            (root_v, root_u)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn is_connected(&mut self, u: usize, v: usize) -> bool {
+        self.find(u) == self.find(v)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Size of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// A label in `0..component_count()` for every element. Labels are
+    /// numbered in order of each component's lowest element.
+    pub fn labels(&self) -> Vec<usize> {
+        let mut label_of_root: HashMap<usize, usize> = HashMap::with_capacity(self.components);
+
+        (0..self.len())
+            .map(|i| {
+                let next = label_of_root.len();
+                *label_of_root.entry(self.root(i)).or_insert(next)
+            })
+            .collect()
+    }
+
+    /// The members of every component, each sorted, ordered by their lowest
+    /// element.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut components = vec![Vec::new(); self.components];
+        for (i, label) in self.labels().into_iter().enumerate() {
+            components[label].push(i);
+        }
+        components
+    }
+}
+
+/// A [`DisjointSet`] over arbitrary keys, which are interned to indices the
+/// first time they are seen.
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    index: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: DisjointSet,
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    pub fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            keys: Vec::new(),
+            sets: DisjointSet::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Interns `key` as a singleton if it is new, and returns its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+
+        let i = self.sets.push();
+        self.index.insert(key.clone(), i);
+        self.keys.push(key);
+        i
+    }
+
+    /// Merges the sets containing `u` and `v`, inserting either key if it
+    /// has not been seen yet.
+    pub fn union(&mut self, u: K, v: K) -> bool {
+        let u = self.insert(u);
+        let v = self.insert(v);
+        self.sets.union(u, v)
+    }
+
+    /// The representative key of the set containing `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let i = *self.index.get(key)?;
+        let root = self.sets.find(i);
+        Some(&self.keys[root])
+    }
+
+    /// `false` if either key has never been inserted.
+    pub fn is_connected(&mut self, u: &K, v: &K) -> bool {
+        match (self.index.get(u), self.index.get(v)) {
+            (Some(&u), Some(&v)) => self.sets.is_connected(u, v),
+            _ => false,
+        }
+    }
+
+    pub fn size_of(&mut self, key: &K) -> Option<usize> {
+        let i = *self.index.get(key)?;
+        Some(self.sets.size_of(i))
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.sets.component_sizes()
+    }
+
+    /// The members of every component, in insertion order.
+    pub fn components(&self) -> Vec<Vec<&K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|members| members.into_iter().map(|i| &self.keys[i]).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_parent_points_to_self() {
+        let uf = DisjointSet::new(5);
+        assert_eq!(uf.len(), 5);
+        assert_eq!(uf.parent.len(), 5);
+        assert_eq!(uf.size.len(), 5);
+
+        for i in 0..5 {
+            assert_eq!(uf.parent[i], i);
+            assert_eq!(uf.size[i], 1);
+        }
+    }
+
+    #[test]
+    fn test_init_find_self() {
+        let mut uf = DisjointSet::new(5);
+        for i in 0..5 {
+            assert_eq!(uf.find(i), i);
+        }
+    }
+
+    #[test]
+    fn test_union_merge_same_root() {
+        let mut uf = DisjointSet::new(5);
+        assert!(uf.union(0, 1));
+        assert_eq!(uf.find(0), uf.find(1));
+    }
+
+    #[test]
+    fn test_union_same_set() {
+        let mut uf = DisjointSet::new(5);
+
+        uf.union(0, 1);
+
+        assert!(!uf.union(0, 1));
+        assert!(!uf.union(1, 0));
+    }
+
+    #[test]
+    fn test_union_transitive() {
+        let mut uf = DisjointSet::new(5);
+
+        uf.union(0, 1);
+        uf.union(1, 2);
+
+        let root = uf.find(0);
+        assert_eq!(uf.find(1), root);
+        assert_eq!(uf.find(2), root);
+    }
+
+    #[test]
+    fn test_union_by_size() {
+        let mut uf = DisjointSet::new(5);
+
+        uf.union(0, 1);
+        uf.union(0, 2);
+
+        uf.union(3, 4);
+
+        uf.union(0, 3);
+
+        // all should be in the same set
+        let root = uf.find(0);
+        for i in 1..5 {
+            assert_eq!(uf.find(i), root);
+        }
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut uf = DisjointSet::new(5);
+
+        // 0->1->2->3
+        uf.union(0, 1);
+        uf.union(1, 2);
+        uf.union(2, 3);
+
+        let root = uf.find(0); // path compression should happen
+
+        // all elements should point directly to root
+        uf.find(0);
+        assert_eq!(uf.parent[0], root);
+    }
+
+    #[test]
+    fn test_is_connected() {
+        let mut uf = DisjointSet::new(5);
+
+        assert!(!uf.is_connected(0, 1));
+
+        uf.union(0, 1);
+
+        assert!(uf.is_connected(0, 1));
+        assert!(uf.is_connected(1, 0)); // symmetric
+
+        assert!(!uf.is_connected(0, 2));
+    }
+
+    #[test]
+    fn test_component_count() {
+        let mut uf = DisjointSet::new(5);
+
+        // start with 5 separate components
+        assert_eq!(uf.component_count(), 5);
+
+        uf.union(0, 1);
+        assert_eq!(uf.component_count(), 4);
+
+        uf.union(2, 3);
+        assert_eq!(uf.component_count(), 3);
+
+        uf.union(0, 2);
+        assert_eq!(uf.component_count(), 2);
+
+        uf.union(1, 4);
+        assert_eq!(uf.component_count(), 1);
+    }
+
+    #[test]
+    fn test_init_component_sizes() {
+        let uf = DisjointSet::new(5);
+
+        let sizes = uf.component_sizes();
+        assert_eq!(sizes.len(), 5);
+
+        for &size in &sizes {
+            assert_eq!(size, 1);
+        }
+    }
+
+    #[test]
+    fn test_component_sizes_after_unions() {
+        let mut uf = DisjointSet::new(5);
+
+        uf.union(0, 1);
+        uf.union(2, 3);
+
+        let mut sizes = uf.component_sizes();
+        sizes.sort();
+
+        // Should have 3 components: size 1, 2, 2
+        assert_eq!(sizes, vec![1, 2, 2]);
+    }
+
+    #[test]
+    fn test_component_sizes_single_component() {
+        let mut uf = DisjointSet::new(5);
+
+        // union all into a single component
+        for i in 1..5 {
+            uf.union(0, i);
+        }
+
+        let sizes = uf.component_sizes();
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes[0], 5);
+    }
+
+    #[test]
+    fn test_multiple_unions_same_elements() {
+        let mut uf = DisjointSet::new(5);
+
+        assert!(uf.union(0, 1));
+        assert!(!uf.union(0, 1)); // already connected
+        assert!(!uf.union(1, 0)); // already connected
+
+        assert_eq!(uf.component_count(), 4);
+    }
+
+    #[test]
+    fn test_complex_scenario() {
+        let mut uf = DisjointSet::new(10);
+
+        // 0->1->2->3
+        // 4->5
+        // 6->7->8
+        // 9
+        uf.union(0, 1);
+        uf.union(1, 2);
+        uf.union(2, 3);
+
+        uf.union(4, 5);
+
+        uf.union(6, 7);
+        uf.union(7, 8);
+
+        assert_eq!(uf.component_count(), 4);
+
+        let mut sizes = uf.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 2, 3, 4]);
+
+        assert!(uf.is_connected(0, 3));
+        assert!(uf.is_connected(4, 5));
+        assert!(uf.is_connected(6, 8));
+        assert!(!uf.is_connected(0, 4));
+        assert!(!uf.is_connected(4, 6));
+    }
+
+    #[test]
+    fn test_empty_disjoint_set() {
+        let uf = DisjointSet::new(0);
+        assert_eq!(uf.component_count(), 0);
+        assert_eq!(uf.component_sizes().len(), 0);
+    }
+
+    #[test]
+    fn test_single_element() {
+        let mut uf = DisjointSet::new(1);
+        assert_eq!(uf.component_count(), 1);
+        assert_eq!(uf.find(0), 0);
+        assert_eq!(uf.component_sizes(), vec![1]);
+    }
+
+    #[test]
+    fn test_union_chain() {
+        let mut uf = DisjointSet::new(100);
+
+        // union in a chain
+        for i in 0..99 {
+            uf.union(i, i + 1);
+        }
+
+        assert_eq!(uf.component_count(), 1);
+        assert_eq!(uf.component_sizes(), vec![100]);
+
+        // all should have the same root
+        let root = uf.find(0);
+        for i in 1..100 {
+            assert_eq!(uf.find(i), root);
+        }
+    }
+
+    #[test]
+    fn test_disjoint_pairs() {
+        let mut uf = DisjointSet::new(10);
+
+        // 5 disjoint pairs
+        for i in (0..10).step_by(2) {
+            uf.union(i, i + 1);
+        }
+
+        assert_eq!(uf.component_count(), 5);
+
+        let sizes = uf.component_sizes();
+        assert_eq!(sizes.len(), 5);
+        for &size in &sizes {
+            assert_eq!(size, 2);
+        }
+    }
+
+    #[test]
+    fn test_size_accuracy() {
+        let mut uf = DisjointSet::new(6);
+
+        uf.union(0, 1);
+        let root1 = uf.find(0);
+        assert_eq!(uf.size[root1], 2);
+
+        uf.union(2, 3);
+        let root2 = uf.find(2);
+        assert_eq!(uf.size[root2], 2);
+
+        uf.union(0, 2); // merge two size-2 sets
+        let root_final = uf.find(0);
+        assert_eq!(uf.size[root_final], 4);
+    }
+
+    #[test]
+    fn test_component_sizes_without_compression() {
+        let mut uf = DisjointSet::new(4);
+
+        // 3 hangs off a non-root until something calls find on it
+        uf.union(0, 1);
+        uf.union(2, 3);
+        uf.union(0, 2);
+
+        let mut sizes = uf.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![4]);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut uf = DisjointSet::new(2);
+        assert_eq!(uf.push(), 2);
+        assert_eq!(uf.len(), 3);
+        assert_eq!(uf.component_count(), 3);
+
+        uf.union(0, 2);
+        assert_eq!(uf.component_count(), 2);
+        assert_eq!(uf.size_of(2), 2);
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut uf = DisjointSet::new(6);
+
+        uf.union(4, 1);
+        uf.union(3, 5);
+        uf.union(5, 0);
+
+        assert_eq!(uf.labels(), vec![0, 1, 2, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut uf = DisjointSet::new(6);
+
+        uf.union(4, 1);
+        uf.union(3, 5);
+        uf.union(5, 0);
+
+        assert_eq!(uf.components(), vec![vec![0, 3, 5], vec![1, 4], vec![2]]);
+    }
+
+    #[test]
+    fn test_keyed_union() {
+        let mut ks: KeyedDisjointSet<&str> = KeyedDisjointSet::new();
+
+        assert!(ks.union("a", "b"));
+        assert!(ks.union("c", "d"));
+        assert!(!ks.union("b", "a"));
+        ks.insert("e");
+
+        assert_eq!(ks.len(), 5);
+        assert_eq!(ks.component_count(), 3);
+        assert!(ks.is_connected(&"a", &"b"));
+        assert!(!ks.is_connected(&"a", &"c"));
+        assert!(!ks.is_connected(&"a", &"z"));
+        assert_eq!(ks.size_of(&"d"), Some(2));
+        assert_eq!(ks.size_of(&"z"), None);
+
+        ks.union("b", "d");
+        let root = *ks.find(&"a").unwrap();
+        assert_eq!(ks.find(&"c"), Some(&root));
+        assert_eq!(
+            ks.components(),
+            vec![vec![&"a", &"b", &"c", &"d"], vec![&"e"]]
+        );
+    }
+
+    #[test]
+    fn test_keyed_points() {
+        let mut ks = KeyedDisjointSet::new();
+
+        ks.union((0, 0), (0, 1));
+        ks.union((5, 5), (5, 6));
+        ks.union((0, 1), (1, 1));
+
+        let mut sizes = ks.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![2, 3]);
+        assert!(ks.contains(&(1, 1)));
+        assert!(!ks.contains(&(2, 2)));
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A piece of the input did not match the expected format.
+    /// `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    /// The input parsed, but its overall shape is wrong for the puzzle
+    /// (e.g. a missing section or an empty file).
+    Shape(String),
+    /// No input file exists at any of the places that were searched.
+    InputNotFound {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    /// Downloading from the puzzle server failed.
+    Fetch { url: String, reason: String },
+    /// Implementations of the same part gave different answers.
+    Disagreement { part: u8 },
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, text: &str, expected: &str) -> Self {
+        Self::Parse {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn shape<S: Into<String>>(message: S) -> Self {
+        Self::Shape(message.into())
+    }
+
+    pub fn fetch<S: Into<String>>(url: &str, reason: S) -> Self {
+        Self::Fetch {
+            url: url.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {}", err),
+            Self::Parse {
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "parse error at {}:{}: expected {}, found {:?}",
+                line, column, expected, text
+            ),
+            Self::Shape(message) => write!(f, "unexpected input shape: {}", message),
+            Self::InputNotFound { year, day, tried } => {
+                write!(f, "no input found for {} day {}, tried:", year, day)?;
+                for path in tried {
+                    write!(f, "\n    {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Fetch { url, reason } => write!(f, "could not fetch {}: {}", url, reason),
+            Self::Disagreement { part } => {
+                write!(f, "implementations of part {} disagree", part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err) => err,
+            err @ Error::InputNotFound { .. } => io::Error::new(io::ErrorKind::NotFound, err),
+            err @ (Error::Fetch { .. } | Error::Disagreement { .. }) => io::Error::other(err),
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = Error::parse(3, 1, "X10", "an action like L68");
+        assert_eq!(
+            err.to_string(),
+            "parse error at 3:1: expected an action like L68, found \"X10\""
+        );
+    }
+
+    #[test]
+    fn test_into_io_error() {
+        let err: io::Error = Error::shape("expected 2 sections, got 1").into();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("expected 2 sections, got 1"));
+
+        let not_found = io::Error::new(io::ErrorKind::NotFound, "missing");
+        let err: io::Error = Error::from(not_found).into();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,429 @@
+use crate::text::dedent;
+use crate::{Error, Result, Solution};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Example inputs and answers pulled out of a puzzle description page.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extracted {
+    /// Every distinct `<pre><code>` block, in page order.
+    pub blocks: Vec<String>,
+    /// `(block index, part, answer)` for each part whose answer was found.
+    pub answers: Vec<(usize, u8, String)>,
+}
+
+/// Pulls the example blocks and expected answers out of a saved puzzle
+/// page.
+///
+/// Each part's `<article>` ends its explanation with the example's answer
+/// as `<code><em>...</em></code>`, so the last such span in the article is
+/// taken as that part's answer. It's paired with the first block in the
+/// same article, or with the page's first block when the article has none
+/// of its own (part two usually reuses part one's example). This is a
+/// heuristic, so check the result before trusting it.
+pub fn extract(html: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+
+    let articles: Vec<&str> = if html.contains("<article") {
+        html.split("<article").skip(1).collect()
+    } else {
+        vec![html]
+    };
+
+    for (i, article) in articles.iter().enumerate() {
+        let mut first_block = None;
+        for (_, block) in between(article, "<pre><code>", "</code></pre>") {
+            let text = unescape(&strip_tags(block));
+            let index = match extracted.blocks.iter().position(|b| *b == text) {
+                Some(index) => index,
+                None => {
+                    extracted.blocks.push(text);
+                    extracted.blocks.len() - 1
+                }
+            };
+            first_block.get_or_insert(index);
+        }
+
+        let answer = emphasized(article, "<code><em>", "</em></code>")
+            .chain(emphasized(article, "<em><code>", "</code></em>"))
+            .max_by_key(|&(position, _)| position)
+            .map(|(_, span)| unescape(span));
+
+        let block = first_block.or((!extracted.blocks.is_empty()).then_some(0));
+        if let (Some(block), Some(answer)) = (block, answer) {
+            extracted.answers.push((block, i as u8 + 1, answer));
+        }
+    }
+
+    extracted
+}
+
+// (offset, slice) for every slice of `text` between `open` and the next
+// `close`
+fn between<'a>(
+    text: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let start = offset + text[offset..].find(open)? + open.len();
+        let end = start + text[start..].find(close)?;
+        offset = end + close.len();
+        Some((start, &text[start..end]))
+    })
+}
+
+// like `between`, but only for plain text directly wrapped in `open` and
+// `close`, so `<code><em>50</em> -&gt; 82</code>` doesn't count
+fn emphasized<'a>(
+    text: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    text.match_indices(open).filter_map(move |(i, _)| {
+        let start = i + open.len();
+        let end = start + text[start..].find('<')?;
+        text[end..]
+            .starts_with(close)
+            .then(|| (start, &text[start..end]))
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+impl Extracted {
+    /// Writes each block to `dir/N.txt`, numbered from 1, and each block's
+    /// answers to a sidecar `dir/N.expected` holding `partP: answer` lines.
+    /// Returns the paths written.
+    pub fn write(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut written = Vec::new();
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            let path = dir.join(format!("{}.txt", i + 1));
+            fs::write(&path, block)?;
+            written.push(path);
+
+            let expected: String = self
+                .answers
+                .iter()
+                .filter(|(index, _, _)| *index == i)
+                .map(|(_, part, answer)| format!("part{}: {}\n", part, answer))
+                .collect();
+            if !expected.is_empty() {
+                let path = dir.join(format!("{}.expected", i + 1));
+                fs::write(&path, expected)?;
+                written.push(path);
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+/// An example input fixture and the answers expected for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// Reads the input at `path` along with its `.expected` sidecar, if
+    /// there is one.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let input = fs::read_to_string(&path)?;
+        let mut example = Self {
+            path,
+            input,
+            part1: None,
+            part2: None,
+        };
+
+        let sidecar = example.path.with_extension("expected");
+        let expected = match fs::read_to_string(&sidecar) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(example),
+            Err(err) => return Err(err.into()),
+        };
+
+        for (i, line) in expected.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let slot = match line.split_once(':') {
+                Some(("part1", answer)) => (&mut example.part1, answer),
+                Some(("part2", answer)) => (&mut example.part2, answer),
+                _ => {
+                    return Err(Error::parse(
+                        i + 1,
+                        1,
+                        line,
+                        "part1: or part2: and an answer",
+                    ));
+                }
+            };
+            *slot.0 = Some(slot.1.trim().to_string());
+        }
+
+        Ok(example)
+    }
+
+    /// Every `*.txt` example in `dir`, sorted by file name.
+    pub fn all<P: AsRef<Path>>(dir: P) -> Result<Vec<Self>> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<_>>()?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+        paths.sort();
+
+        paths.iter().map(Self::load).collect()
+    }
+
+    /// The expected answer for `part`, parsed as `T`.
+    pub fn expected<T: FromStr>(&self, part: u8) -> Option<T> {
+        let answer = match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        };
+        answer?.parse().ok()
+    }
+}
+
+/// Where an [`aoc_test!`](crate::aoc_test) example comes from.
+pub enum Source {
+    /// A path relative to the day crate's manifest directory.
+    File(PathBuf),
+    /// Inline input, which may be indented (see
+    /// [`dedent`](crate::text::dedent)).
+    Input(&'static str),
+}
+
+/// Parses `source` with `S::parse`, exactly as real input is parsed, and
+/// asserts that `part` gives `expected`. Without `expected` the answer comes
+/// from the file's `.expected` sidecar.
+pub fn check<S: Solution>(source: Source, part: u8, expected: Option<String>) {
+    // a file is loaded once, for both its input and its answers
+    let (input, example) = match source {
+        Source::File(path) => {
+            let example =
+                Example::load(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+            (example.input.clone(), Some((path, example)))
+        }
+        Source::Input(input) => (dedent(input), None),
+    };
+
+    let parsed = S::parse(&input)
+        .unwrap_or_else(|err| panic!("{} day {} example: {}", S::YEAR, S::DAY, err));
+
+    let expected = expected.unwrap_or_else(|| {
+        // the macro only leaves the answer out for files
+        let (path, example) = example.expect("expected answer for an inline example");
+        let answer = if part == 1 {
+            example.part1
+        } else {
+            example.part2
+        };
+        answer.unwrap_or_else(|| panic!("{} has no part{} answer", path.display(), part))
+    });
+
+    let actual = match part {
+        1 => S::part1(&parsed).to_string(),
+        2 => S::part2(&parsed).to_string(),
+        _ => panic!("no part {}", part),
+    };
+    assert_eq!(actual, expected, "{} day {} part {}", S::YEAR, S::DAY, part);
+}
+
+/// Generates `example_part1` and `example_part2` tests that run a
+/// [`Solution`] on an example through its real `parse`:
+///
+/// ```ignore
+/// // answers from examples/1.expected
+/// aoc_utils::aoc_test!(Day5, file: "examples/1.txt");
+/// aoc_utils::aoc_test!(Day5, file: "examples/1.txt", part1: 3, part2: 14);
+/// // inline input may be indented
+/// aoc_utils::aoc_test!(Day5, input: "
+///     3-5
+///     10-14
+///
+///     1
+///     5
+/// ", part2: 8);
+/// ```
+///
+/// `file` paths are relative to the calling crate's manifest. With explicit
+/// answers, only the parts given get a test. The test names are fixed, so a
+/// second invocation needs its own `mod`.
+#[macro_export]
+macro_rules! aoc_test {
+    (@file $path:expr) => {
+        $crate::examples::Source::File(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+    (@part $name:ident, $solution:ty, $source:expr, $part:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            $crate::examples::check::<$solution>($source, $part, $expected);
+        }
+    };
+    ($solution:ty, file: $path:expr $(,)?) => {
+        $crate::aoc_test!(@part example_part1, $solution, $crate::aoc_test!(@file $path), 1, None);
+        $crate::aoc_test!(@part example_part2, $solution, $crate::aoc_test!(@file $path), 2, None);
+    };
+    ($solution:ty, file: $path:expr $(, part1: $part1:expr)? $(, part2: $part2:expr)? $(,)?) => {
+        $(
+            $crate::aoc_test!(@part example_part1, $solution, $crate::aoc_test!(@file $path), 1,
+                Some(($part1).to_string()));
+        )?
+        $(
+            $crate::aoc_test!(@part example_part2, $solution, $crate::aoc_test!(@file $path), 2,
+                Some(($part2).to_string()));
+        )?
+    };
+    ($solution:ty, input: $input:expr $(, part1: $part1:expr)? $(, part2: $part2:expr)? $(,)?) => {
+        $(
+            $crate::aoc_test!(@part example_part1, $solution,
+                $crate::examples::Source::Input($input), 1, Some(($part1).to_string()));
+        )?
+        $(
+            $crate::aoc_test!(@part example_part2, $solution,
+                $crate::examples::Source::Input($input), 2, Some(($part2).to_string()));
+        )?
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_lines_str;
+    use std::env;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>For example, suppose the attached document contained the following rotations:</p>
+<pre><code>L68
+L30
+R48
+</code></pre>
+<p>Following these rotations would cause the dial to move as follows:</p>
+<pre><code><em>50</em> -&gt; 82</code></pre>
+<p>Because the dial points at <code>0</code> a total of three times during this process, the password in this example is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In this example, the new password would be <code><em>6</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let extracted = extract(PAGE);
+        assert_eq!(
+            extracted.blocks,
+            vec!["L68\nL30\nR48\n".to_string(), "50 -> 82".to_string()]
+        );
+        assert_eq!(
+            extracted.answers,
+            vec![(0, 1, "3".to_string()), (0, 2, "6".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_extract_part_two_example() {
+        let page = r#"<article><pre><code>a</code></pre><p>gives <code><em>1</em></code></p></article>
+<article><pre><code>a</code></pre><pre><code>b</code></pre><p>gives <em><code>2</code></em></p></article>"#;
+        let extracted = extract(page);
+        assert_eq!(extracted.blocks, vec!["a", "b"]);
+        assert_eq!(
+            extracted.answers,
+            vec![(0, 1, "1".to_string()), (0, 2, "2".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_write_and_load() {
+        let dir = env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let written = extract(PAGE).write(&dir).unwrap();
+        assert_eq!(
+            written,
+            vec![dir.join("1.txt"), dir.join("1.expected"), dir.join("2.txt")]
+        );
+
+        let examples = Example::all(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "L68\nL30\nR48\n");
+        assert_eq!(examples[0].expected::<i32>(1), Some(3));
+        assert_eq!(examples[0].expected::<i32>(2), Some(6));
+        assert_eq!(examples[1].part1, None);
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(read_lines_str(input))
+        }
+
+        fn part1(input: &Self::Input) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input) -> usize {
+            input.iter().map(String::len).sum()
+        }
+    }
+
+    mod from_input {
+        use super::Lines;
+
+        crate::aoc_test!(Lines, input: "
+            ab
+             cd
+        ", part1: 2, part2: 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "2000 day 2 part 1")]
+    fn test_check_mismatch() {
+        check::<Lines>(Source::Input("a\n"), 1, Some("7".to_string()));
+    }
+}
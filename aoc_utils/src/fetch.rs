@@ -0,0 +1,246 @@
+use crate::http;
+use crate::{Error, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Shortest gap between two requests to the server, across every process
+/// sharing the cache directory.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+// holds the time of the last request, in milliseconds since the epoch
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+/// Downloads puzzle input and caches it as `YYYY/dayN.txt` under a cache
+/// directory. Input that is already cached is never fetched again.
+///
+/// The cache uses the same layout as `AOC_INPUT_DIR`, so pointing that
+/// variable at the cache directory makes fetched input visible to
+/// [`resolve_input`](crate::input::resolve_input).
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Fetcher {
+    pub fn new<S: Into<String>, P: Into<PathBuf>>(base_url: S, cache_dir: P) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: None,
+            cache_dir: cache_dir.into(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    /// Configured from the environment:
+    ///
+    /// - `AOC_BASE_URL`, defaulting to [`DEFAULT_BASE_URL`]
+    /// - `AOC_SESSION`, or else the contents of `AOC_SESSION_FILE` or
+    ///   `~/.config/aoc/session`
+    /// - `AOC_CACHE_DIR`, or else `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`
+    pub fn from_env() -> Self {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let mut fetcher = Self::new(base_url, default_cache_dir());
+        fetcher.session = http::session_from_env();
+        fetcher
+    }
+
+    pub fn with_session<S: Into<String>>(mut self, token: S) -> Self {
+        self.session = Some(token.into());
+        self
+    }
+
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// The cached input, if it has been fetched before.
+    pub fn cached(&self, year: u16, day: u8) -> Option<PathBuf> {
+        let path = self.cache_path(year, day);
+        path.is_file().then_some(path)
+    }
+
+    /// Path to the input for `year` and `day`, downloading it first if it
+    /// isn't cached yet. Failed downloads leave nothing in the cache.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<PathBuf> {
+        if let Some(path) = self.cached(year, day) {
+            return Ok(path);
+        }
+
+        let url = self.url(year, day);
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| http::missing_session(&url))?;
+
+        fs::create_dir_all(&self.cache_dir)?;
+        self.wait_for_rate_limit()?;
+        let response = http::get(&url, session)?;
+        if response.status != 200 {
+            return Err(Error::fetch(
+                &url,
+                format!("server answered {}", response.status),
+            ));
+        }
+        let body = response.body;
+        if body.is_empty() {
+            return Err(Error::fetch(&url, "empty response"));
+        }
+
+        // write then rename, so a half-written file is never mistaken for
+        // cached input
+        let path = self.cache_path(year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        let partial = path.with_extension("partial");
+        fs::write(&partial, body)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(path)
+    }
+
+    fn wait_for_rate_limit(&self) -> Result<()> {
+        let stamp = self.cache_dir.join(LAST_REQUEST_FILE);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+
+        if let Some(last) = last {
+            let next = last + self.min_interval;
+            let now = since_epoch();
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        // round up, so truncating to millis never lets the next request early
+        let now = since_epoch();
+        fs::write(&stamp, now.as_nanos().div_ceil(1_000_000).to_string())?;
+        Ok(())
+    }
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+pub(crate) fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| http::home_dir().join(".cache"))
+        .join("aoc")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+    use std::time::Instant;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_fetch_then_cache() {
+        let dir = TempDir::new("cache");
+        let (url, requests) = stub::serve(vec![(200, "1 2\n3 4\n")]);
+        let fetcher = Fetcher::new(url, &dir.0)
+            .with_session("abc123")
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(fetcher.cached(2025, 3), None);
+        let path = fetcher.fetch(2025, 3).unwrap();
+        assert_eq!(path, dir.0.join("2025/day3.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+
+        // served from the cache, the stub would hang on a second connection
+        assert_eq!(fetcher.fetch(2025, 3).unwrap(), path);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_failed_fetch_is_not_cached() {
+        let dir = TempDir::new("not_cached");
+        let (url, _) = stub::serve(vec![(404, "not unlocked yet"), (200, "")]);
+        let fetcher = Fetcher::new(url, &dir.0)
+            .with_session("abc123")
+            .with_min_interval(Duration::ZERO);
+
+        let err = fetcher.fetch(2025, 25).unwrap_err();
+        assert!(err.to_string().contains("server answered 404"), "{}", err);
+        assert!(fetcher.fetch(2025, 25).is_err());
+        assert_eq!(fetcher.cached(2025, 25), None);
+    }
+
+    #[test]
+    fn test_fetch_needs_session() {
+        let dir = TempDir::new("session");
+        // nothing listens here, so this fails before connecting or it
+        // would report a connection error instead
+        let fetcher = Fetcher::new("http://127.0.0.1:9", &dir.0);
+        let err = fetcher.fetch(2025, 1).unwrap_err();
+        assert!(err.to_string().contains("no session token"), "{}", err);
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let dir = TempDir::new("rate_limit");
+        let (url, requests) = stub::serve(vec![(200, "a\n"), (200, "b\n")]);
+        let fetcher = Fetcher::new(url, &dir.0)
+            .with_session("abc123")
+            .with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch(2025, 1).unwrap();
+        fetcher.fetch(2025, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
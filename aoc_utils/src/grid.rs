@@ -0,0 +1,288 @@
+use crate::geom::{Direction4, Direction8};
+use crate::{Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+type Coord = (usize, usize);
+
+/// A rectangular grid stored row-major in a single buffer. Positions are
+/// `(row, col)` pairs with `(0, 0)` in the top-left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            return Err(Error::shape(format!(
+                "expected {}x{} = {} cells, got {}",
+                width,
+                height,
+                width * height,
+                cells.len()
+            )));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row_index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::shape(format!(
+                    "row {} has {} columns, expected {}",
+                    row_index + 1,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.in_bounds(row, col) {
+            Some(&self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.in_bounds(row, col) {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// Position one step from `(row, col)` along `offset`, if it is in bounds.
+    pub fn step(&self, position: Coord, offset: (isize, isize)) -> Option<Coord> {
+        let (row, col) = crate::geom::checked_offset(position, offset)?;
+        self.in_bounds(row, col).then_some((row, col))
+    }
+
+    /// In-bounds orthogonal neighbors of `(row, col)`, clockwise from up.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = Coord> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step((row, col), direction.offset()))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors of `(row, col)`, clockwise
+    /// from up.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = Coord> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step((row, col), direction.offset()))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and a zero-width grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {col} out of range for a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Every cell along with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    /// Position of the first cell, in row-major order, matching `predicate`.
+    pub fn find<F>(&self, predicate: F) -> Option<Coord>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Coord) -> &T {
+        assert!(
+            self.in_bounds(row, col),
+            "({}, {}) out of bounds for {}x{} grid",
+            row,
+            col,
+            self.width,
+            self.height
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Coord) -> &mut T {
+        assert!(
+            self.in_bounds(row, col),
+            "({}, {}) out of bounds for {}x{} grid",
+            row,
+            col,
+            self.width,
+            self.height
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_index, row) in self.rows().enumerate() {
+            if row_index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::from_rows(vec![
+            "abc".chars().collect(),
+            "def".chars().collect(),
+            "ghi".chars().collect(),
+            "jkl".chars().collect(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get(3, 0), Some(&'j'));
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+    }
+
+    #[test]
+    fn test_from_rows_ragged() {
+        let result = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+        assert!(matches!(result, Err(Error::Shape(_))));
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = sample();
+        *grid.get_mut(0, 0).unwrap() = 'z';
+        grid[(3, 2)] = 'y';
+        assert_eq!(grid.row(0), &['z', 'b', 'c']);
+        assert_eq!(grid.row(3), &['j', 'k', 'y']);
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        let grid = sample();
+        let corner: Vec<_> = grid.neighbors4(0, 0).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let middle: Vec<_> = grid.neighbors4(1, 1).collect();
+        assert_eq!(middle, vec![(0, 1), (1, 2), (2, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let grid = sample();
+        assert_eq!(grid.neighbors8(0, 0).count(), 3);
+        assert_eq!(grid.neighbors8(3, 1).count(), 5);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def", "ghi", "jkl"]);
+
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, vec!["adgj", "behk", "cfil"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 4 out of range")]
+    fn test_column_out_of_range() {
+        // would otherwise wrap into the next row
+        let _ = sample().column(4);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = sample();
+        assert_eq!(grid.find(|&c| c == 'h'), Some((2, 1)));
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(sample().to_string(), "abc\ndef\nghi\njkl");
+    }
+}
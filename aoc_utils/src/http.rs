@@ -0,0 +1,352 @@
+// just enough HTTP to talk to the puzzle server. plain http is spoken
+// directly, which is all a local stub server needs; https goes through curl
+// rather than pulling in a TLS stack
+
+use crate::fetch::{SESSION_FILE_VAR, SESSION_VAR};
+use crate::{Error, Result};
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const USER_AGENT: &str = "aoc_utils";
+
+/// How long to wait to connect, and for each write or read after that, so
+/// a stalled server fails the request instead of hanging it.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub(crate) fn get(url: &str, session: &str) -> Result<Response> {
+    send(url, session, None)
+}
+
+/// POSTs `form`, which must already be urlencoded.
+pub(crate) fn post_form(url: &str, session: &str, form: &str) -> Result<Response> {
+    send(url, session, Some(form))
+}
+
+/// `AOC_SESSION`, or else the contents of `AOC_SESSION_FILE` or
+/// `~/.config/aoc/session`.
+pub(crate) fn session_from_env() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(session_file()).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+pub(crate) fn session_file() -> PathBuf {
+    env::var_os(SESSION_FILE_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home_dir().join(".config/aoc/session"))
+}
+
+pub(crate) fn home_dir() -> PathBuf {
+    env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(env::temp_dir)
+}
+
+pub(crate) fn missing_session(url: &str) -> Error {
+    Error::fetch(
+        url,
+        format!(
+            "no session token, set {} or write it to {}",
+            SESSION_VAR,
+            session_file().display()
+        ),
+    )
+}
+
+fn send(url: &str, session: &str, form: Option<&str>) -> Result<Response> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        send_plain(url, rest, session, form, TIMEOUT)
+    } else if url.starts_with("https://") {
+        send_curl(url, session, form)
+    } else {
+        Err(Error::fetch(
+            url,
+            "only http:// and https:// URLs are supported",
+        ))
+    }
+}
+
+fn connect(address: &str, timeout: Duration) -> io::Result<TcpStream> {
+    let mut last_err = io::Error::new(io::ErrorKind::NotFound, "address resolved to nothing");
+    for address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = err,
+        }
+    }
+    Err(last_err)
+}
+
+fn send_plain(
+    url: &str,
+    rest: &str,
+    session: &str,
+    form: Option<&str>,
+    timeout: Duration,
+) -> Result<Response> {
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let network_error = |err: io::Error| match err.kind() {
+        // a read that times out reports WouldBlock on unix
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => {
+            Error::fetch(url, format!("no response within {:?}", timeout))
+        }
+        _ => Error::fetch(url, err.to_string()),
+    };
+
+    let mut stream = connect(&address, timeout).map_err(network_error)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let method = if form.is_some() { "POST" } else { "GET" };
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n",
+        method, path, authority, USER_AGENT, session
+    );
+    if let Some(form) = form {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", form.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(form.unwrap_or(""));
+    stream
+        .write_all(request.as_bytes())
+        .map_err(network_error)?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(network_error)?;
+    let (status, body) =
+        parse_response(&raw).ok_or_else(|| Error::fetch(url, "malformed response"))?;
+    let body = String::from_utf8(body).map_err(|_| Error::fetch(url, "response is not UTF-8"))?;
+
+    Ok(Response { status, body })
+}
+
+fn send_curl(url: &str, session: &str, form: Option<&str>) -> Result<Response> {
+    let spawn_error =
+        |err: std::io::Error| Error::fetch(url, format!("could not run curl: {}", err));
+
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        // the cookie goes through stdin so the token never shows up in `ps`
+        .args(["--header", "@-"]);
+    if let Some(form) = form {
+        command.args(["--data", form]);
+    }
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session)?;
+    }
+    let output = child.wait_with_output().map_err(spawn_error)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::fetch(url, stderr.trim()));
+    }
+
+    let stdout =
+        String::from_utf8(output.stdout).map_err(|_| Error::fetch(url, "response is not UTF-8"))?;
+    // --write-out appends the status code on its own line
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .and_then(|(body, code)| Some((body, code.trim().parse().ok()?)))
+        .ok_or_else(|| Error::fetch(url, "curl printed no status code"))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// (status, body) of a complete HTTP/1.1 response
+fn parse_response(raw: &[u8]) -> Option<(u16, Vec<u8>)> {
+    let split = raw.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = std::str::from_utf8(&raw[..split]).ok()?;
+    let body = &raw[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+
+    let mut chunked = false;
+    let mut length = None;
+    for line in lines {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            length = value.parse::<usize>().ok();
+        }
+    }
+
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        let end = length.unwrap_or(body.len()).min(body.len());
+        body[..end].to_vec()
+    };
+
+    Some((status, body))
+}
+
+fn decode_chunked(mut data: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+
+    loop {
+        let line_end = data.windows(2).position(|w| w == b"\r\n")?;
+        let size_field = std::str::from_utf8(&data[..line_end]).ok()?;
+        // ignore chunk extensions after ';'
+        let size_field = size_field.split(';').next()?.trim();
+        let size = usize::from_str_radix(size_field, 16).ok()?;
+        data = &data[line_end + 2..];
+
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(data.get(..size)?);
+        data = data.get(size + 2..)?;
+    }
+}
+
+/// A local server for tests, standing in for the puzzle site.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    pub(crate) type Requests = Arc<Mutex<Vec<String>>>;
+
+    /// Answers each connection with the next canned `(status, body)` and
+    /// records the raw requests it received. Returns the base URL.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&mut stream);
+                seen.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+
+        let head_end = loop {
+            if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                break i + 4;
+            }
+            let n = stream.read(&mut buf).unwrap();
+            if n == 0 {
+                break request.len();
+            }
+            request.extend_from_slice(&buf[..n]);
+        };
+
+        let head = String::from_utf8_lossy(&request[..head_end]).to_lowercase();
+        let length: usize = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(0);
+        while request.len() < head_end + length {
+            let n = stream.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        String::from_utf8_lossy(&request).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello, and more";
+        assert_eq!(parse_response(raw), Some((200, b"hello".to_vec())));
+
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n6;x=y\r\npedia \r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw), Some((200, b"Wikipedia ".to_vec())));
+
+        assert_eq!(parse_response(b"garbage"), None);
+    }
+
+    #[test]
+    fn test_post_form() {
+        let (url, requests) = stub::serve(vec![(200, "ok")]);
+        let response =
+            post_form(&format!("{}/answer", url), "abc123", "level=1&answer=42").unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, "ok"));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=42"));
+    }
+
+    #[test]
+    fn test_stalled_server_times_out() {
+        // connections queue up in the backlog but nothing ever answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let url = format!("http://{}/", address);
+
+        let timeout = Duration::from_millis(100);
+        let err = send_plain(&url, &format!("{}/", address), "abc123", None, timeout)
+            .err()
+            .expect("nothing answered");
+        assert!(err.to_string().contains("no response within"), "{}", err);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{Error, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The variable naming one year's own input directory, e.g.
+/// `AOC_INPUT_DIR_2024`.
+pub fn year_input_dir_var(year: u16) -> String {
+    format!("{}_{}", INPUT_DIR_VAR, year)
+}
+
+/// Every place an input might live, in the order [`resolve_input`] checks
+/// them:
+///
+/// 1. `input.txt` in the day crate's `CARGO_MANIFEST_DIR`
+/// 2. `dayN/input.txt` or `dayN.txt` under `year_dir`, which normally comes
+///    from `AOC_INPUT_DIR_YYYY`
+/// 3. `YYYY/dayN/input.txt` or `YYYY/dayN.txt` under `input_dir`, which
+///    normally comes from `AOC_INPUT_DIR`
+/// 4. the CLI argument, either the input file itself or a directory holding
+///    `input.txt`
+pub fn candidates(
+    year: u16,
+    day: u8,
+    manifest_dir: Option<&Path>,
+    year_dir: Option<&Path>,
+    input_dir: Option<&Path>,
+    cli_arg: Option<&Path>,
+) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(dir) = manifest_dir {
+        paths.push(dir.join("input.txt"));
+    }
+
+    let year_dirs = year_dir
+        .map(Path::to_path_buf)
+        .into_iter()
+        .chain(input_dir.map(|dir| dir.join(year.to_string())));
+    for dir in year_dirs {
+        paths.push(dir.join(format!("day{}", day)).join("input.txt"));
+        paths.push(dir.join(format!("day{}.txt", day)));
+    }
+
+    if let Some(arg) = cli_arg {
+        if arg.is_dir() {
+            paths.push(arg.join("input.txt"));
+        } else {
+            paths.push(arg.to_path_buf());
+        }
+    }
+
+    paths
+}
+
+/// The first existing file among [`candidates`], reading `year_dir` from
+/// `AOC_INPUT_DIR_YYYY` and `input_dir` from `AOC_INPUT_DIR`. The error
+/// lists every path that was tried.
+pub fn resolve_input(
+    year: u16,
+    day: u8,
+    manifest_dir: Option<&Path>,
+    cli_arg: Option<&Path>,
+) -> Result<PathBuf> {
+    let dirs = InputDirs {
+        year: env::var_os(year_input_dir_var(year)).map(PathBuf::from),
+        shared: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+    };
+    resolve_from(year, day, manifest_dir, &dirs, cli_arg)
+}
+
+#[derive(Default)]
+struct InputDirs {
+    year: Option<PathBuf>,
+    shared: Option<PathBuf>,
+}
+
+fn resolve_from(
+    year: u16,
+    day: u8,
+    manifest_dir: Option<&Path>,
+    dirs: &InputDirs,
+    cli_arg: Option<&Path>,
+) -> Result<PathBuf> {
+    let tried = candidates(
+        year,
+        day,
+        manifest_dir,
+        dirs.year.as_deref(),
+        dirs.shared.as_deref(),
+        cli_arg,
+    );
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::InputNotFound { year, day, tried }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn touch(&self, relative: &str) -> PathBuf {
+            let path = self.0.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "input").unwrap();
+            path
+        }
+    }
+
+    fn shared(dir: PathBuf) -> InputDirs {
+        InputDirs {
+            year: None,
+            shared: Some(dir),
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_manifest_dir_first() {
+        let dir = TempDir::new("manifest_first");
+        let manifest = dir.touch("2025/day3/input.txt");
+        let from_env = dir.touch("env/2025/day3.txt");
+        let cli = dir.touch("cli.txt");
+
+        let found = resolve_from(
+            2025,
+            3,
+            Some(manifest.parent().unwrap()),
+            &shared(dir.0.join("env")),
+            Some(&cli),
+        )
+        .unwrap();
+        assert_eq!(found, manifest);
+        assert_ne!(found, from_env);
+    }
+
+    #[test]
+    fn test_input_dir_layouts() {
+        let dir = TempDir::new("input_dir");
+        let flat = dir.touch("2024/day7.txt");
+        let found = resolve_from(
+            2024,
+            7,
+            Some(&dir.0.join("nowhere")),
+            &shared(dir.0.clone()),
+            None,
+        );
+        assert_eq!(found.unwrap(), flat);
+
+        let nested = dir.touch("2024/day7/input.txt");
+        let found = resolve_from(2024, 7, None, &shared(dir.0.clone()), None);
+        assert_eq!(found.unwrap(), nested);
+    }
+
+    #[test]
+    fn test_year_dir_before_shared() {
+        let dir = TempDir::new("year_dir");
+        dir.touch("shared/2023/day2.txt");
+        let own = dir.touch("inputs-2023/day2.txt");
+
+        let dirs = InputDirs {
+            year: Some(dir.0.join("inputs-2023")),
+            shared: Some(dir.0.join("shared")),
+        };
+        assert_eq!(resolve_from(2023, 2, None, &dirs, None).unwrap(), own);
+        assert_eq!(year_input_dir_var(2023), "AOC_INPUT_DIR_2023");
+    }
+
+    #[test]
+    fn test_cli_arg_last() {
+        let dir = TempDir::new("cli_last");
+        let file = dir.touch("mine.txt");
+        let none = InputDirs::default();
+        let found = resolve_from(2025, 1, Some(&dir.0.join("day1")), &none, Some(&file));
+        assert_eq!(found.unwrap(), file);
+
+        let in_dir = dir.touch("somewhere/input.txt");
+        let found = resolve_from(2025, 1, None, &none, Some(&dir.0.join("somewhere")));
+        assert_eq!(found.unwrap(), in_dir);
+    }
+
+    #[test]
+    fn test_not_found_lists_every_path() {
+        let dir = TempDir::new("not_found");
+        let err = resolve_from(
+            2025,
+            9,
+            Some(&dir.0.join("2025/day9")),
+            &shared(dir.0.join("inputs")),
+            Some(&dir.0.join("missing.txt")),
+        )
+        .unwrap_err();
+
+        match &err {
+            Error::InputNotFound { year, day, tried } => {
+                assert_eq!((*year, *day), (2025, 9));
+                assert_eq!(tried.len(), 4);
+            }
+            other => panic!("expected InputNotFound, got {:?}", other),
+        }
+
+        let message = err.to_string();
+        assert!(message.starts_with("no input found for 2025 day 9, tried:"));
+        assert!(message.contains("2025/day9/input.txt"));
+        assert!(message.contains("inputs/2025/day9.txt"));
+        assert!(message.contains("missing.txt"));
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + fmt::Debug {
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+    /// Number of values in `start..=end`.
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn checked_succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128 + 1) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive
+/// ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // (start, end), inclusive
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of disjoint ranges.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::span(start, end))
+            .sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The ranges strictly between consecutive members of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            // ranges are never adjacent, so both of these exist
+            let start = pair[0].1.checked_succ().unwrap();
+            let end = pair[1].0.checked_pred().unwrap();
+            start..=end
+        })
+    }
+
+    /// O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Adds every value in `range`, merging with any ranges it overlaps or
+    /// touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // first range that ends at or after start - 1, i.e. could touch
+        let lo = self
+            .ranges
+            .partition_point(|&(_, e)| e.checked_succ().is_some_and(|next| next < start));
+        // first range that starts after end + 1
+        let hi = self
+            .ranges
+            .partition_point(|&(s, _)| end.checked_succ().is_none_or(|next| s <= next));
+
+        if lo < hi {
+            start = start.min(self.ranges[lo].0);
+            end = end.max(self.ranges[hi - 1].1);
+        }
+        self.ranges.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Removes every value in `range`.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // ranges that overlap start..=end
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+        if lo >= hi {
+            return;
+        }
+
+        let mut kept = Vec::with_capacity(2);
+        let (first_start, _) = self.ranges[lo];
+        let (_, last_end) = self.ranges[hi - 1];
+        if first_start < start {
+            kept.push((first_start, start.checked_pred().unwrap()));
+        }
+        if last_end > end {
+            kept.push((end.checked_succ().unwrap(), last_end));
+        }
+        self.ranges.splice(lo..hi, kept);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(|r| r.into_inner()).collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let s = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(ranges(&s), vec![(3, 5), (10, 20)]);
+        assert_eq!(s.len(), 14);
+    }
+
+    #[test]
+    fn test_insert_merges_adjacent() {
+        let s = set(&[(1, 2), (3, 4), (6, 6)]);
+        assert_eq!(ranges(&s), vec![(1, 4), (6, 6)]);
+
+        let s = set(&[(6, 6), (1, 2), (3, 5)]);
+        assert_eq!(ranges(&s), vec![(1, 6)]);
+    }
+
+    #[test]
+    fn test_insert_spanning() {
+        let mut s = set(&[(1, 2), (5, 6), (9, 10), (20, 30)]);
+        s.insert(4..=11);
+        assert_eq!(ranges(&s), vec![(1, 2), (4, 11), (20, 30)]);
+
+        s.insert(0..=0);
+        assert_eq!(ranges(&s), vec![(0, 2), (4, 11), (20, 30)]);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        s.insert(8..=7);
+        assert_eq!(s.range_count(), 3);
+    }
+
+    #[test]
+    fn test_insert_at_type_limits() {
+        let mut s: IntervalSet<u8> = IntervalSet::new();
+        s.insert(250..=255);
+        s.insert(0..=3);
+        s.insert(4..=249);
+        assert_eq!(s.range_count(), 1);
+        assert_eq!(s.len(), 256);
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(3, 5), (10, 20)]);
+        for (value, expected) in [
+            (1, false),
+            (3, true),
+            (5, true),
+            (6, false),
+            (9, false),
+            (10, true),
+            (17, true),
+            (20, true),
+            (21, false),
+        ] {
+            assert_eq!(s.contains(value), expected, "contains({})", value);
+        }
+        assert!(!IntervalSet::<i64>::new().contains(0));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(5..=6);
+        assert_eq!(ranges(&s), vec![(1, 4), (7, 10), (20, 30)]);
+
+        s.remove(9..=25);
+        assert_eq!(ranges(&s), vec![(1, 4), (7, 8), (26, 30)]);
+
+        s.remove(0..=100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (14, 20)]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![(1, 20)]);
+        assert_eq!(
+            ranges(&a.intersection(&b)),
+            vec![(4, 5), (10, 11), (14, 15)]
+        );
+        assert_eq!(ranges(&a.difference(&b)), vec![(1, 3), (12, 13)]);
+        assert_eq!(ranges(&b.difference(&a)), vec![(6, 9), (16, 20)]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(-5, -1), (3, 5), (10, 20)]);
+        let gaps: Vec<_> = s.gaps().map(|r| r.into_inner()).collect();
+        assert_eq!(gaps, vec![(0, 2), (6, 9)]);
+    }
+}
@@ -1,91 +1,435 @@
+pub mod bench;
+pub mod dsu;
+mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geom;
+mod grid;
+mod http;
+pub mod input;
+pub mod intervals;
+mod json;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod testing;
+pub mod text;
+
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use solution::{Solution, SolutionEntry};
+
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-pub fn read_file<P: AsRef<Path>>(path: P) -> io::Result<BufReader<File>> {
+// every helper comes in three flavours: `foo_str` does the work on text,
+// `foo_reader` reads everything from a `BufRead` first, and `foo` opens a
+// file and hands it to `foo_reader`
+
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<BufReader<File>> {
     let file = File::open(path)?;
     Ok(BufReader::new(file))
 }
 
-pub fn read_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
-    let reader = read_file(path)?;
-    reader.lines().collect()
+fn read_text<R: BufRead>(mut reader: R) -> Result<String> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+pub fn read_lines_str(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+pub fn read_lines_reader<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    Ok(reader.lines().collect::<std::io::Result<_>>()?)
 }
 
-pub fn parse_lines<P, T, F>(path: P, parser: F) -> io::Result<Vec<T>>
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    read_lines_reader(read_file(path)?)
+}
+
+pub fn parse_lines_str<T, F>(text: &str, parser: F) -> Vec<T>
 where
-    P: AsRef<Path>,
     F: Fn(&str) -> Option<T>,
 {
-    let lines = read_lines(path)?;
-    Ok(lines.iter().filter_map(|line| parser(line)).collect())
+    text.lines().filter_map(parser).collect()
 }
 
-pub fn parse_file<P, T, F>(path: P, parser: F) -> io::Result<T>
+pub fn parse_lines_reader<R, T, F>(reader: R, parser: F) -> Result<Vec<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    Ok(parse_lines_str(&read_text(reader)?, parser))
+}
+
+pub fn parse_lines<P, T, F>(path: P, parser: F) -> Result<Vec<T>>
 where
     P: AsRef<Path>,
-    F: Fn(Vec<String>) -> io::Result<T>,
+    F: Fn(&str) -> Option<T>,
 {
-    let lines = read_lines(path)?;
-    parser(lines)
+    parse_lines_reader(read_file(path)?, parser)
 }
 
-pub fn parse_lines_split<P, T, F>(path: P, separator: &str, parser: F) -> io::Result<Vec<T>>
+pub fn parse_file_str<T, F>(text: &str, parser: F) -> Result<T>
+where
+    F: Fn(Vec<String>) -> Result<T>,
+{
+    parser(read_lines_str(text))
+}
+
+pub fn parse_file_reader<R, T, F>(reader: R, parser: F) -> Result<T>
+where
+    R: BufRead,
+    F: Fn(Vec<String>) -> Result<T>,
+{
+    parser(read_lines_reader(reader)?)
+}
+
+pub fn parse_file<P, T, F>(path: P, parser: F) -> Result<T>
 where
     P: AsRef<Path>,
+    F: Fn(Vec<String>) -> Result<T>,
+{
+    parse_file_reader(read_file(path)?, parser)
+}
+
+pub fn parse_lines_split_str<T, F>(text: &str, separator: &str, parser: F) -> Vec<T>
+where
     F: Fn(&str) -> Option<T>,
 {
-    let lines = read_lines(path)?;
-    Ok(lines
-        .iter()
+    text.lines()
         .flat_map(|line| line.split(separator))
-        .filter_map(|item| parser(item))
-        .collect())
+        .filter_map(parser)
+        .collect()
 }
 
-pub fn parse_grid<P, T, F>(path: P, parser: F) -> io::Result<Vec<Vec<T>>>
+pub fn parse_lines_split_reader<R, T, F>(reader: R, separator: &str, parser: F) -> Result<Vec<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    Ok(parse_lines_split_str(
+        &read_text(reader)?,
+        separator,
+        parser,
+    ))
+}
+
+pub fn parse_lines_split<P, T, F>(path: P, separator: &str, parser: F) -> Result<Vec<T>>
 where
     P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_split_reader(read_file(path)?, separator, parser)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejected {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl std::fmt::Display for Rejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}:{}: {:?}", self.line, self.column, self.text)
+    }
+}
+
+/// Values from a lenient parse, along with every non-blank item the parser
+/// turned down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parsed<T> {
+    pub values: Vec<T>,
+    pub rejected: Vec<Rejected>,
+}
+
+// (line, column, item), both 1-based, skipping blank items
+fn numbered_items<'a>(
+    text: &'a str,
+    separator: Option<&'a str>,
+) -> impl Iterator<Item = (usize, usize, &'a str)> + 'a {
+    text.lines().enumerate().flat_map(move |(row, line)| {
+        let items: Vec<&str> = match separator {
+            Some(separator) => line.split(separator).collect(),
+            None => vec![line],
+        };
+
+        let mut column = 1;
+        items.into_iter().filter_map(move |item| {
+            let start = column;
+            column += item.chars().count() + separator.map_or(0, |s| s.chars().count());
+            if item.trim().is_empty() {
+                None
+            } else {
+                Some((row + 1, start, item))
+            }
+        })
+    })
+}
+
+fn parse_items_strict<T, F>(
+    text: &str,
+    separator: Option<&str>,
+    expected: &str,
+    parser: F,
+) -> Result<Vec<T>>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    numbered_items(text, separator)
+        .map(|(line, column, item)| {
+            parser(item).ok_or_else(|| Error::parse(line, column, item, expected))
+        })
+        .collect()
+}
+
+fn parse_items_lenient<T, F>(text: &str, separator: Option<&str>, parser: F) -> Parsed<T>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    let mut values = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (line, column, item) in numbered_items(text, separator) {
+        match parser(item) {
+            Some(value) => values.push(value),
+            None => rejected.push(Rejected {
+                line,
+                column,
+                text: item.to_string(),
+            }),
+        }
+    }
+
+    Parsed { values, rejected }
+}
+
+/// Like [`parse_lines_str`], but fails on the first non-blank line the
+/// parser rejects instead of dropping it. `expected` says what a line
+/// should look like, for the error: "a number", "a point like 1,2,3".
+pub fn parse_lines_strict_str<T, F>(text: &str, expected: &str, parser: F) -> Result<Vec<T>>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    parse_items_strict(text, None, expected, parser)
+}
+
+pub fn parse_lines_strict_reader<R, T, F>(reader: R, expected: &str, parser: F) -> Result<Vec<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_strict_str(&read_text(reader)?, expected, parser)
+}
+
+/// Like [`parse_lines`], but fails on the first non-blank line the parser
+/// rejects instead of dropping it, as [`parse_lines_strict_str`] does.
+pub fn parse_lines_strict<P, T, F>(path: P, expected: &str, parser: F) -> Result<Vec<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_strict_reader(read_file(path)?, expected, parser)
+}
+
+/// Like [`parse_lines_str`], but also reports the lines the parser
+/// rejected.
+pub fn parse_lines_lenient_str<T, F>(text: &str, parser: F) -> Parsed<T>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    parse_items_lenient(text, None, parser)
+}
+
+pub fn parse_lines_lenient_reader<R, T, F>(reader: R, parser: F) -> Result<Parsed<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    Ok(parse_lines_lenient_str(&read_text(reader)?, parser))
+}
+
+/// Like [`parse_lines`], but also reports the lines the parser rejected.
+pub fn parse_lines_lenient<P, T, F>(path: P, parser: F) -> Result<Parsed<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_lenient_reader(read_file(path)?, parser)
+}
+
+/// Like [`parse_lines_split_str`], but fails on the first non-blank item
+/// the parser rejects instead of dropping it. `expected` says what an item
+/// should look like, for the error.
+pub fn parse_lines_split_strict_str<T, F>(
+    text: &str,
+    separator: &str,
+    expected: &str,
+    parser: F,
+) -> Result<Vec<T>>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    parse_items_strict(text, Some(separator), expected, parser)
+}
+
+pub fn parse_lines_split_strict_reader<R, T, F>(
+    reader: R,
+    separator: &str,
+    expected: &str,
+    parser: F,
+) -> Result<Vec<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_split_strict_str(&read_text(reader)?, separator, expected, parser)
+}
+
+/// Like [`parse_lines_split`], but fails on the first non-blank item the
+/// parser rejects instead of dropping it, as
+/// [`parse_lines_split_strict_str`] does.
+pub fn parse_lines_split_strict<P, T, F>(
+    path: P,
+    separator: &str,
+    expected: &str,
+    parser: F,
+) -> Result<Vec<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_split_strict_reader(read_file(path)?, separator, expected, parser)
+}
+
+/// Like [`parse_lines_split_str`], but also reports the items the parser
+/// rejected.
+pub fn parse_lines_split_lenient_str<T, F>(text: &str, separator: &str, parser: F) -> Parsed<T>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    parse_items_lenient(text, Some(separator), parser)
+}
+
+pub fn parse_lines_split_lenient_reader<R, T, F>(
+    reader: R,
+    separator: &str,
+    parser: F,
+) -> Result<Parsed<T>>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<T>,
+{
+    Ok(parse_lines_split_lenient_str(
+        &read_text(reader)?,
+        separator,
+        parser,
+    ))
+}
+
+/// Like [`parse_lines_split`], but also reports the items the parser rejected.
+pub fn parse_lines_split_lenient<P, T, F>(path: P, separator: &str, parser: F) -> Result<Parsed<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
+    parse_lines_split_lenient_reader(read_file(path)?, separator, parser)
+}
+
+/// Parses one cell per character, leading spaces included. Use
+/// [`parse_grid_dedented_str`] for grids indented with the code around them.
+pub fn parse_grid_str<T, F>(text: &str, parser: F) -> Result<Grid<T>>
+where
     F: Fn(usize, usize, char) -> T,
 {
-    let lines = read_lines(path)?;
-    Ok(lines
-        .iter()
+    let rows = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
         .enumerate()
-        .filter(|(_, line)| !line.trim().is_empty())
         .map(|(row_index, line)| {
             line.chars()
                 .enumerate()
                 .map(|(col_index, c)| parser(row_index, col_index, c))
                 .collect()
         })
-        .collect())
+        .collect();
+    Grid::from_rows(rows)
+}
+
+pub fn parse_grid_reader<R, T, F>(reader: R, parser: F) -> Result<Grid<T>>
+where
+    R: BufRead,
+    F: Fn(usize, usize, char) -> T,
+{
+    parse_grid_str(&read_text(reader)?, parser)
+}
+
+pub fn parse_grid<P, T, F>(path: P, parser: F) -> Result<Grid<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(usize, usize, char) -> T,
+{
+    parse_grid_reader(read_file(path)?, parser)
+}
+
+/// Like [`parse_grid_str`], but first strips the indentation every row
+/// shares (see [`text::dedent`]), so rows keep their alignment and a
+/// leading `.` or space that only some rows have stays a cell.
+pub fn parse_grid_dedented_str<T, F>(text: &str, parser: F) -> Result<Grid<T>>
+where
+    F: Fn(usize, usize, char) -> T,
+{
+    parse_grid_str(&text::dedent(text), parser)
+}
+
+pub fn parse_grid_dedented_reader<R, T, F>(reader: R, parser: F) -> Result<Grid<T>>
+where
+    R: BufRead,
+    F: Fn(usize, usize, char) -> T,
+{
+    parse_grid_dedented_str(&read_text(reader)?, parser)
+}
+
+pub fn parse_grid_dedented<P, T, F>(path: P, parser: F) -> Result<Grid<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(usize, usize, char) -> T,
+{
+    parse_grid_dedented_reader(read_file(path)?, parser)
 }
 
 pub trait FromGrid: Sized {
     type Element;
 
     fn parse_element(row: usize, col: usize, c: char) -> Self::Element;
-    fn from_grid(grid: Vec<Vec<Self::Element>>) -> Self;
+    fn from_grid(grid: Grid<Self::Element>) -> Result<Self>;
+}
+
+pub fn parse_grid_from_str<T: FromGrid>(text: &str) -> Result<T> {
+    T::from_grid(parse_grid_str(text, T::parse_element)?)
 }
 
-pub fn parse_grid_from<P, T>(path: P) -> io::Result<T>
+pub fn parse_grid_from_reader<R: BufRead, T: FromGrid>(reader: R) -> Result<T> {
+    parse_grid_from_str(&read_text(reader)?)
+}
+
+pub fn parse_grid_from<P, T>(path: P) -> Result<T>
 where
     P: AsRef<Path>,
     T: FromGrid,
 {
-    let grid_data = parse_grid(path, T::parse_element)?;
-    Ok(T::from_grid(grid_data))
+    parse_grid_from_reader(read_file(path)?)
 }
 
-pub fn parse_sections_split<P, T, F>(path: P, parser: F) -> io::Result<T>
+pub fn parse_sections_split_str<T, F>(text: &str, parser: F) -> Result<T>
 where
-    P: AsRef<Path>,
-    F: Fn(Vec<Vec<String>>) -> io::Result<T>,
+    F: Fn(Vec<Vec<String>>) -> Result<T>,
 {
-    let content = std::fs::read_to_string(path)?;
-
-    let sections = content
+    let sections = text
         .split("\n\n")
         .map(|s| s.lines().map(String::from).collect())
         .collect();
@@ -93,6 +437,22 @@ where
     parser(sections)
 }
 
+pub fn parse_sections_split_reader<R, T, F>(reader: R, parser: F) -> Result<T>
+where
+    R: BufRead,
+    F: Fn(Vec<Vec<String>>) -> Result<T>,
+{
+    parse_sections_split_str(&read_text(reader)?, parser)
+}
+
+pub fn parse_sections_split<P, T, F>(path: P, parser: F) -> Result<T>
+where
+    P: AsRef<Path>,
+    F: Fn(Vec<Vec<String>>) -> Result<T>,
+{
+    parse_sections_split_reader(read_file(path)?, parser)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +473,150 @@ mod tests {
 
         fs::remove_file(temp_file).unwrap();
     }
+
+    fn parse_number(s: &str) -> Option<u32> {
+        s.trim().parse().ok()
+    }
+
+    #[test]
+    fn test_parse_lines_strict() {
+        let temp_file = "test_temp_strict.txt";
+        fs::write(temp_file, "1\n\n2\nthree\n4\n").unwrap();
+
+        let result = parse_lines_strict(temp_file, "a number", parse_number);
+        fs::remove_file(temp_file).unwrap();
+
+        match result {
+            Err(Error::Parse {
+                line,
+                column,
+                text,
+                expected,
+            }) => {
+                assert_eq!(line, 4);
+                assert_eq!(column, 1);
+                assert_eq!(text, "three");
+                assert_eq!(expected, "a number");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_lenient() {
+        let temp_file = "test_temp_lenient.txt";
+        fs::write(temp_file, "1\n\n2\nthree\n4\n").unwrap();
+
+        let parsed = parse_lines_lenient(temp_file, parse_number).unwrap();
+        fs::remove_file(temp_file).unwrap();
+
+        assert_eq!(parsed.values, vec![1, 2, 4]);
+        assert_eq!(
+            parsed.rejected,
+            vec![Rejected {
+                line: 4,
+                column: 1,
+                text: "three".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_split_strict_reports_column() {
+        let temp_file = "test_temp_split_strict.txt";
+        fs::write(temp_file, "1,2\n3,x4,5\n").unwrap();
+
+        let result = parse_lines_split_strict(temp_file, ",", "a number", parse_number);
+        fs::remove_file(temp_file).unwrap();
+
+        match result {
+            Err(Error::Parse { line, column, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(column, 3);
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_split_lenient() {
+        let temp_file = "test_temp_split_lenient.txt";
+        fs::write(temp_file, "1,2,,bad\n").unwrap();
+
+        let parsed = parse_lines_split_lenient(temp_file, ",", parse_number).unwrap();
+        fs::remove_file(temp_file).unwrap();
+
+        assert_eq!(parsed.values, vec![1, 2]);
+        assert_eq!(parsed.rejected.len(), 1);
+        assert_eq!(parsed.rejected[0].column, 6);
+    }
+
+    #[test]
+    fn test_str_and_reader_agree() {
+        let text = "1,2\n3,x\n\n4\n";
+        let from_str = parse_lines_split_str(text, ",", parse_number);
+        let from_reader = parse_lines_split_reader(text.as_bytes(), ",", parse_number).unwrap();
+        assert_eq!(from_str, vec![1, 2, 3, 4]);
+        assert_eq!(from_reader, from_str);
+
+        let lenient = parse_lines_lenient_str(text, parse_number);
+        assert_eq!(lenient.values, vec![4]);
+        assert_eq!(lenient.rejected.len(), 2);
+        assert!(
+            parse_lines_strict_reader(std::io::Cursor::new(text), "a number", parse_number)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_str() {
+        let grid = parse_grid_str("ab\ncd\n\n", |row, col, c| (row, col, c)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 0)], (1, 0, 'c'));
+
+        assert!(matches!(
+            parse_grid_str("ab\nc\n", |_, _, c| c),
+            Err(Error::Shape(_))
+        ));
+
+        // leading spaces are cells
+        let grid = parse_grid_str("  .#\n  #.\n", |_, _, c| c).unwrap();
+        assert_eq!(grid.width(), 4);
+    }
+
+    #[test]
+    fn test_parse_grid_dedented_str() {
+        // the first column's `.` is a cell, not indentation
+        let grid = parse_grid_dedented_str(
+            "
+            .#.
+            ..#
+            ###
+            ",
+            |_, _, c| c,
+        )
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(0, 0)], '.');
+        assert_eq!(grid.to_string(), ".#.\n..#\n###");
+
+        // a row indented further keeps its extra leading space
+        let grid = parse_grid_dedented_str("\n   ab\n  cd \n", |_, _, c| c).unwrap();
+        assert_eq!(grid.to_string(), " ab\ncd ");
+
+        let grid = parse_grid_dedented_reader("  .#\n  #.\n".as_bytes(), |_, _, c| c).unwrap();
+        assert_eq!(grid.to_string(), ".#\n#.");
+    }
+
+    #[test]
+    fn test_parse_sections_split_str() {
+        let sections = parse_sections_split_str("a\nb\n\nc\n", Ok).unwrap();
+        assert_eq!(
+            sections,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()]
+            ]
+        );
+    }
 }
@@ -0,0 +1,454 @@
+use crate::Grid;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+type Coord = (usize, usize);
+
+/// Everything a search learned about the nodes it reached.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    /// Best known cost from the start to every reached node.
+    pub dist: HashMap<N, C>,
+    /// The node each reached node was first (or best) reached from. The
+    /// start has no entry.
+    pub prev: HashMap<N, N>,
+    /// Number of nodes expanded.
+    pub visited: usize,
+    /// The goal node, for searches that stop at one.
+    pub goal: Option<N>,
+}
+
+impl<N, C> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy,
+{
+    fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            visited: 0,
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// The path from the start to `node`, inclusive at both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        let mut curr = node;
+        while let Some(prev) = self.prev.get(curr) {
+            path.push(prev.clone());
+            curr = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`, visiting every reachable node. `dist`
+/// holds the number of steps to each node.
+pub fn bfs<N, F, I>(start: N, mut successors: F) -> SearchResult<N, usize>
+where
+    N: Hash + Eq + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    result.dist.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        result.visited += 1;
+        let next_dist = result.dist[&node] + 1;
+
+        for next in successors(&node) {
+            if result.dist.contains_key(&next) {
+                continue;
+            }
+            result.dist.insert(next.clone(), next_dist);
+            result.prev.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+
+    result
+}
+
+// min-heap entry ordered by (priority, insertion order) so nodes themselves
+// don't need to be Ord
+struct Entry<N, C> {
+    priority: C,
+    seq: usize,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reverse for min-heap (BinaryHeap is max-heap by default)
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn best_first<N, C, F, I, H, G>(
+    start: N,
+    mut successors: F,
+    heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: Fn(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut heap = BinaryHeap::new();
+    let mut seq = 0;
+
+    result.dist.insert(start.clone(), C::default());
+    heap.push(Entry {
+        priority: heuristic(&start),
+        seq,
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        // stale entry, a cheaper path was already expanded
+        if result.dist.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+
+        result.visited += 1;
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if result
+                .dist
+                .get(&next)
+                .is_some_and(|&best| best <= next_cost)
+            {
+                continue;
+            }
+
+            seq += 1;
+            result.dist.insert(next.clone(), next_cost);
+            result.prev.insert(next.clone(), node.clone());
+            heap.push(Entry {
+                priority: next_cost + heuristic(&next),
+                seq,
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm from `start`, visiting every reachable node.
+/// `successors` yields each neighbor along with the cost of the edge to it.
+pub fn dijkstra<N, C, F, I>(start: N, successors: F) -> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), |_| false)
+}
+
+/// A* from `start`, stopping at the first node for which `is_goal` holds.
+/// `heuristic` must never overestimate the remaining cost. `goal` is `None`
+/// if no goal is reachable.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    successors: F,
+    heuristic: H,
+    is_goal: G,
+) -> SearchResult<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: Fn(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    best_first(start, successors, heuristic, is_goal)
+}
+
+#[derive(Debug, Clone)]
+pub struct PathCounts<N> {
+    /// Number of distinct paths from the start to any target.
+    pub total: u64,
+    /// Number of paths from each reached node to any target.
+    pub from: HashMap<N, u64>,
+    /// Number of nodes expanded.
+    pub visited: usize,
+}
+
+/// Counts the distinct paths from `start` to nodes where `is_target` holds,
+/// in a graph that must be acyclic. Targets are not expanded further. Each
+/// node is expanded once, so the cost is linear in the reachable graph even
+/// when the number of paths is exponential.
+pub fn count_paths_dag<N, F, I, T>(start: N, mut successors: F, mut is_target: T) -> PathCounts<N>
+where
+    N: Hash + Eq + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    T: FnMut(&N) -> bool,
+{
+    let mut from: HashMap<N, u64> = HashMap::new();
+    let mut children: HashMap<N, Vec<N>> = HashMap::new();
+    let mut stack = vec![start.clone()];
+    let mut visited = 0;
+
+    // iterative post-order, so deep graphs can't blow the call stack
+    while let Some(node) = stack.last().cloned() {
+        if from.contains_key(&node) {
+            stack.pop();
+            continue;
+        }
+
+        if is_target(&node) {
+            visited += 1;
+            from.insert(node, 1);
+            stack.pop();
+            continue;
+        }
+
+        match children.get(&node) {
+            None => {
+                let next: Vec<N> = successors(&node).into_iter().collect();
+                stack.extend(next.iter().filter(|n| !from.contains_key(n)).cloned());
+                children.insert(node, next);
+            }
+            Some(next) => {
+                visited += 1;
+                let count = next.iter().map(|n| from.get(n).copied().unwrap_or(0)).sum();
+                children.remove(&node);
+                from.insert(node, count);
+                stack.pop();
+            }
+        }
+    }
+
+    PathCounts {
+        total: from.get(&start).copied().unwrap_or(0),
+        from,
+        visited,
+    }
+}
+
+/// Successor function for orthogonal moves between cells of `grid` that
+/// satisfy `passable`.
+pub fn grid_successors4<'a, T, P>(
+    grid: &'a Grid<T>,
+    passable: P,
+) -> impl FnMut(&Coord) -> Vec<Coord> + 'a
+where
+    P: Fn(&T) -> bool + 'a,
+{
+    move |&(row, col)| {
+        grid.neighbors4(row, col)
+            .filter(|&coord| passable(&grid[coord]))
+            .collect()
+    }
+}
+
+/// Successor function for orthogonal and diagonal moves between cells of
+/// `grid` that satisfy `passable`.
+pub fn grid_successors8<'a, T, P>(
+    grid: &'a Grid<T>,
+    passable: P,
+) -> impl FnMut(&Coord) -> Vec<Coord> + 'a
+where
+    P: Fn(&T) -> bool + 'a,
+{
+    move |&(row, col)| {
+        grid.neighbors8(row, col)
+            .filter(|&coord| passable(&grid[coord]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Grid<char> {
+        let rows = ["S.#.....", ".##.###.", "....#...", "##.##.#E"]
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+        Grid::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn test_bfs_grid() {
+        let grid = maze();
+        let result = bfs((0, 0), grid_successors4(&grid, |&c| c != '#'));
+
+        assert_eq!(result.distance(&(0, 0)), Some(0));
+        assert_eq!(result.distance(&(3, 7)), Some(14));
+        assert_eq!(result.distance(&(0, 2)), None);
+
+        let path = result.path_to(&(3, 7)).unwrap();
+        assert_eq!(path.len(), 15);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[14], (3, 7));
+        for pair in path.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+        }
+    }
+
+    #[test]
+    fn test_bfs_visits_reachable_once() {
+        let result = bfs(0u32, |&n| if n < 10 { vec![n + 1, n + 2] } else { vec![] });
+        assert_eq!(result.visited, 12);
+        assert_eq!(result.distance(&11), Some(6));
+        assert_eq!(result.path_to(&0), Some(vec![0]));
+    }
+
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra('a', weighted);
+
+        assert_eq!(result.distance(&'e'), Some(20));
+        assert_eq!(result.distance(&'d'), Some(20));
+        assert_eq!(result.distance(&'f'), Some(11));
+        assert_eq!(result.path_to(&'e'), Some(vec!['a', 'c', 'f', 'e']));
+        assert_eq!(result.visited, 6);
+        assert_eq!(result.goal, None);
+    }
+
+    #[test]
+    fn test_astar_matches_bfs() {
+        let grid = maze();
+        let goal = (3, 7);
+        let mut next = grid_successors4(&grid, |&c| c != '#');
+
+        let result = astar(
+            (0, 0),
+            |node| next(node).into_iter().map(|n| (n, 1)),
+            |&(r, c): &Coord| r.abs_diff(goal.0) + c.abs_diff(goal.1),
+            |&node| node == goal,
+        );
+
+        assert_eq!(result.goal, Some(goal));
+        assert_eq!(result.distance(&goal), Some(14));
+        assert_eq!(result.path_to(&goal).unwrap().len(), 15);
+    }
+
+    #[test]
+    fn test_grid_successors_borrow_local_state() {
+        let grid = maze();
+        // the predicate borrows `walls` rather than owning it
+        let walls = String::from("#");
+        let mut next = grid_successors8(&grid, |&c| !walls.contains(c));
+
+        let mut neighbors = next(&(0, 0));
+        neighbors.sort();
+        let expected: Vec<_> = [(0, 1), (1, 0), (1, 1)]
+            .into_iter()
+            .filter(|&coord| grid[coord] != '#')
+            .collect();
+        assert_eq!(neighbors, expected);
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        let result = astar(
+            0u32,
+            |&n| vec![(n + 1, 1u32)].into_iter().filter(|&(n, _)| n < 5),
+            |_| 0,
+            |&n| n == 9,
+        );
+        assert_eq!(result.goal, None);
+        assert_eq!(result.visited, 5);
+    }
+
+    #[test]
+    fn test_count_paths_dag() {
+        // lattice paths through a 3x3 block of moves: C(6, 3)
+        let counts = count_paths_dag(
+            (0, 0),
+            |&(r, c): &Coord| {
+                let mut next = Vec::new();
+                if r < 3 {
+                    next.push((r + 1, c));
+                }
+                if c < 3 {
+                    next.push((r, c + 1));
+                }
+                next
+            },
+            |&node| node == (3, 3),
+        );
+
+        assert_eq!(counts.total, 20);
+        assert_eq!(counts.from[&(2, 2)], 2);
+        assert_eq!(counts.visited, 16);
+    }
+
+    #[test]
+    fn test_count_paths_dag_dead_ends() {
+        let counts = count_paths_dag(
+            0u32,
+            |&n| if n < 4 { vec![n + 1, 10] } else { vec![] },
+            |&n| n == 4,
+        );
+        assert_eq!(counts.total, 1);
+        assert_eq!(counts.from[&10], 0);
+    }
+}
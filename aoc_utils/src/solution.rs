@@ -0,0 +1,383 @@
+use crate::bench::{self, Timing};
+use crate::input::resolve_input;
+use crate::{Error, Result};
+use std::any::Any;
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Named implementations of one part, for cross-checking.
+pub type Implementations<I, A> = Vec<(&'static str, fn(&I) -> A)>;
+
+/// One day's puzzle: how to parse the input and how to answer both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the puzzle input, already read into memory.
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Other ways to solve part 1, by name. The day binary runs each of them
+    /// next to `part1` and fails if any answer differs.
+    fn part1_implementations() -> Implementations<Self::Input, Self::Answer1> {
+        Vec::new()
+    }
+
+    /// Other ways to solve part 2, like [`Solution::part1_implementations`].
+    fn part2_implementations() -> Implementations<Self::Input, Self::Answer2> {
+        Vec::new()
+    }
+
+    /// Where the input lives by default, relative to the workspace root.
+    fn input_path() -> PathBuf {
+        PathBuf::from(format!("{}/day{}/input.txt", Self::YEAR, Self::DAY))
+    }
+}
+
+/// One implementation's answer and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    pub name: &'static str,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// Runs `main` (named "default") and every alternative on `input`, in that
+/// order.
+pub fn cross_check<I, A: Display>(
+    input: &I,
+    main: fn(&I) -> A,
+    alternatives: Implementations<I, A>,
+) -> Vec<Checked> {
+    std::iter::once(("default", main))
+        .chain(alternatives)
+        .map(|(name, solve)| {
+            let start = Instant::now();
+            let answer = black_box(solve(input)).to_string();
+            Checked {
+                name,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// Prints the answer, or every implementation's answer and time if they
+/// differ. Returns whether they agreed.
+pub fn report(part: u8, checked: &[Checked]) -> bool {
+    if checked.iter().all(|c| c.answer == checked[0].answer) {
+        println!("Part {}: {}", part, checked[0].answer);
+        return true;
+    }
+
+    println!("Part {}: implementations disagree", part);
+    let name_width = checked.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let answer_width = checked.iter().map(|c| c.answer.len()).max().unwrap_or(0);
+    for c in checked {
+        let marker = if c.answer == checked[0].answer {
+            ' '
+        } else {
+            '!'
+        };
+        println!(
+            "  {} {:<name_width$}  {:>answer_width$}  {:>10.3?}",
+            marker, c.name, c.answer, c.time
+        );
+    }
+    false
+}
+
+/// Finds the input with [`resolve_input`], parses it and prints both
+/// answers, after checking every registered implementation agrees.
+pub fn run<S: Solution>(manifest_dir: Option<&Path>, cli_arg: Option<&Path>) -> Result<()> {
+    let path = resolve_input(S::YEAR, S::DAY, manifest_dir, cli_arg)?;
+    let input = S::parse(&fs::read_to_string(path)?)?;
+
+    let agreed1 = report(
+        1,
+        &cross_check(&input, S::part1, S::part1_implementations()),
+    );
+    let agreed2 = report(
+        2,
+        &cross_check(&input, S::part2, S::part2_implementations()),
+    );
+
+    match (agreed1, agreed2) {
+        (false, _) => Err(Error::Disagreement { part: 1 }),
+        (_, false) => Err(Error::Disagreement { part: 2 }),
+        _ => Ok(()),
+    }
+}
+
+/// The whole of a day binary: `aoc_utils::main!(day1_2025::Day1);` defines a
+/// `main` that finds the input next to the calling crate's manifest (see
+/// [`resolve_input`]) and prints both answers. An optional first argument
+/// points at a different input file or directory.
+#[macro_export]
+macro_rules! main {
+    ($solution:ty) => {
+        fn main() -> ::std::process::ExitCode {
+            let cli_arg = ::std::env::args_os().nth(1).map(::std::path::PathBuf::from);
+            let result = $crate::solution::run::<$solution>(
+                Some(::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))),
+                cli_arg.as_deref(),
+            );
+
+            match result {
+                Ok(()) => ::std::process::ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ::std::process::ExitCode::FAILURE
+                }
+            }
+        }
+    };
+}
+
+/// Parsed input for a [`SolutionEntry`], with its concrete type erased.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// A [`Solution`] with its associated types erased, so days with different
+/// input and answer types can share one registry.
+#[derive(Clone, Copy)]
+pub struct SolutionEntry {
+    pub year: u16,
+    pub day: u8,
+    default_input: fn() -> PathBuf,
+    parse: fn(&str) -> Result<ParsedInput>,
+    part1: fn(&ParsedInput) -> String,
+    part2: fn(&ParsedInput) -> String,
+    check: fn(u8, &ParsedInput) -> Vec<Checked>,
+    time_alternatives: fn(u8, usize, &ParsedInput) -> Vec<(&'static str, Timing)>,
+}
+
+fn erased_parse<S: Solution>(input: &str) -> Result<ParsedInput>
+where
+    S::Input: 'static,
+{
+    Ok(ParsedInput(Box::new(S::parse(input)?)))
+}
+
+fn erased_part1<S: Solution>(input: &ParsedInput) -> String
+where
+    S::Input: 'static,
+{
+    S::part1(downcast::<S>(input)).to_string()
+}
+
+fn erased_part2<S: Solution>(input: &ParsedInput) -> String
+where
+    S::Input: 'static,
+{
+    S::part2(downcast::<S>(input)).to_string()
+}
+
+fn erased_check<S: Solution>(part: u8, input: &ParsedInput) -> Vec<Checked>
+where
+    S::Input: 'static,
+{
+    let input = downcast::<S>(input);
+    match part {
+        1 => cross_check(input, S::part1, S::part1_implementations()),
+        2 => cross_check(input, S::part2, S::part2_implementations()),
+        _ => panic!("no part {}", part),
+    }
+}
+
+fn erased_time_alternatives<S: Solution>(
+    part: u8,
+    runs: usize,
+    input: &ParsedInput,
+) -> Vec<(&'static str, Timing)>
+where
+    S::Input: 'static,
+{
+    let input = downcast::<S>(input);
+    match part {
+        1 => time_each(input, S::part1_implementations(), runs),
+        2 => time_each(input, S::part2_implementations(), runs),
+        _ => panic!("no part {}", part),
+    }
+}
+
+fn time_each<I, A>(
+    input: &I,
+    implementations: Implementations<I, A>,
+    runs: usize,
+) -> Vec<(&'static str, Timing)> {
+    implementations
+        .into_iter()
+        .map(|(name, solve)| (name, bench::time(runs, || solve(input))))
+        .collect()
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input.0.downcast_ref().unwrap_or_else(|| {
+        panic!(
+            "input passed to {} day {} was parsed by a different solution",
+            S::YEAR,
+            S::DAY
+        )
+    })
+}
+
+impl SolutionEntry {
+    pub fn of<S: Solution>() -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            default_input: S::input_path,
+            parse: erased_parse::<S>,
+            part1: erased_part1::<S>,
+            part2: erased_part2::<S>,
+            check: erased_check::<S>,
+            time_alternatives: erased_time_alternatives::<S>,
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        (self.default_input)()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedInput> {
+        (self.parse)(input)
+    }
+
+    pub fn part1(&self, input: &ParsedInput) -> String {
+        (self.part1)(input)
+    }
+
+    pub fn part2(&self, input: &ParsedInput) -> String {
+        (self.part2)(input)
+    }
+
+    /// Answer for `part`, which must be 1 or 2.
+    pub fn part(&self, part: u8, input: &ParsedInput) -> String {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => panic!("no part {}", part),
+        }
+    }
+
+    /// Runs [`cross_check`] on `part`, which must be 1 or 2: the default
+    /// implementation first, then every registered alternative.
+    pub fn cross_check(&self, part: u8, input: &ParsedInput) -> Vec<Checked> {
+        (self.check)(part, input)
+    }
+
+    /// Times every registered alternative of `part`, which must be 1 or 2,
+    /// with [`bench::time`]. The default implementation isn't included.
+    pub fn time_alternatives(
+        &self,
+        part: u8,
+        runs: usize,
+        input: &ParsedInput,
+    ) -> Vec<(&'static str, Timing)> {
+        (self.time_alternatives)(part, runs, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_lines_str;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(parse_lines_str(input, |line| line.parse().ok()))
+        }
+
+        fn part1(input: &Self::Input) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> usize {
+            input.len()
+        }
+
+        fn part1_implementations() -> Implementations<Self::Input, Self::Answer1> {
+            vec![("copied", |input| input.iter().copied().sum())]
+        }
+
+        fn part2_implementations() -> Implementations<Self::Input, Self::Answer2> {
+            vec![("off by one", |input| input.len() + 1)]
+        }
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(Sum::input_path(), PathBuf::from("2000/day1/input.txt"));
+    }
+
+    #[test]
+    fn test_entry_runs_erased_solution() {
+        let entry = SolutionEntry::of::<Sum>();
+        let input = entry.parse("1\n2\n3\n").unwrap();
+
+        assert_eq!((entry.year, entry.day), (2000, 1));
+        assert_eq!(entry.part1(&input), "6");
+        assert_eq!(entry.part(2, &input), "3");
+
+        let answers: Vec<_> = entry
+            .cross_check(2, &input)
+            .into_iter()
+            .map(|c| (c.name, c.answer))
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                ("default", "3".to_string()),
+                ("off by one", "4".to_string())
+            ]
+        );
+
+        let timed: Vec<_> = [1, 2]
+            .into_iter()
+            .flat_map(|part| entry.time_alternatives(part, 3, &input))
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(timed, ["copied", "off by one"]);
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let input = Sum::parse("1\n2\n3\n").unwrap();
+
+        let checked = cross_check(&input, Sum::part1, Sum::part1_implementations());
+        let names: Vec<_> = checked
+            .iter()
+            .map(|c| (c.name, c.answer.as_str()))
+            .collect();
+        assert_eq!(names, [("default", "6"), ("copied", "6")]);
+        assert!(report(1, &checked));
+
+        let checked = cross_check(&input, Sum::part2, Sum::part2_implementations());
+        assert_eq!(checked[1].answer, "4");
+        assert!(!report(2, &checked));
+    }
+}
@@ -0,0 +1,616 @@
+use crate::fetch::{self, BASE_URL_VAR, DEFAULT_BASE_URL};
+use crate::http;
+use crate::{Error, Result};
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const LEDGER_VAR: &str = "AOC_LEDGER";
+
+/// What the server said about a submitted answer, or why it never got to
+/// say anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt, with the wait the
+    /// server asked for when it gave one.
+    RateLimited(Option<Duration>),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    /// A page none of the above matched.
+    Unknown,
+    /// Not sent, because the ledger ruled the answer out.
+    Refused,
+    /// Sent, or meant to be, without getting a page back: no session, a
+    /// network error or a status other than 200.
+    Failed,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML the server answers a submission
+    /// with.
+    pub fn from_page(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Right
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Self::TooHigh
+            } else if page.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait(page))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            Self::Right => "right",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::RateLimited(_) => "rate-limited",
+            Self::WrongLevel => "wrong-level",
+            Self::Unknown => "unknown",
+            Self::Refused => "refused",
+            Self::Failed => "failed",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        Some(match tag {
+            "right" => Self::Right,
+            "wrong" => Self::Wrong,
+            "too-high" => Self::TooHigh,
+            "too-low" => Self::TooLow,
+            "rate-limited" => Self::RateLimited(None),
+            "wrong-level" => Self::WrongLevel,
+            "unknown" => Self::Unknown,
+            "refused" => Self::Refused,
+            "failed" => Self::Failed,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "right answer"),
+            Self::Wrong => write!(f, "wrong answer"),
+            Self::TooHigh => write!(f, "wrong answer, too high"),
+            Self::TooLow => write!(f, "wrong answer, too low"),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::WrongLevel => write!(f, "part already solved or not unlocked"),
+            Self::Unknown => write!(f, "unrecognized response"),
+            Self::Refused => write!(f, "not submitted"),
+            Self::Failed => write!(f, "no response"),
+        }
+    }
+}
+
+// "You have 1m 5s left to wait." or "You have 37s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in page[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// One attempt at submitting, as stored in the [`Ledger`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why the [`Ledger`] won't let an answer be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong,
+    /// At or above an answer the server already called too high.
+    AtLeast {
+        too_high: String,
+    },
+    /// At or below an answer the server already called too low.
+    AtMost {
+        too_low: String,
+    },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Self::KnownWrong => write!(f, "already submitted and known to be wrong"),
+            Self::AtLeast { too_high } => write!(f, "{} was already too high", too_high),
+            Self::AtMost { too_low } => write!(f, "{} was already too low", too_low),
+        }
+    }
+}
+
+/// Every answer ever submitted or refused, one tab-separated line per
+/// attempt: `at year day part verdict answer`. Refused and failed attempts
+/// are kept for the record but never count against an answer.
+#[derive(Debug, Clone)]
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`. A missing file is an empty ledger.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let attempts = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_attempt(line).ok_or_else(|| Error::parse(i + 1, 1, line, "a ledger entry"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { path, attempts })
+    }
+
+    /// `AOC_LEDGER`, or else `answers.tsv` in the fetch cache directory.
+    pub fn default_path() -> PathBuf {
+        env::var_os(LEDGER_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| fetch::default_cache_dir().join("answers.tsv"))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// How many parts of a day have an accepted answer.
+    pub fn stars(&self, year: u16, day: u8) -> usize {
+        [1, 2]
+            .into_iter()
+            .filter(|&part| {
+                self.attempts(year, day, part)
+                    .any(|a| a.verdict == Verdict::Right)
+            })
+            .count()
+    }
+
+    /// Whether `answer` is worth submitting, given what the server already
+    /// said about earlier attempts. Bounds only apply to integer answers.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Refusal> {
+        let value = answer.parse::<i128>().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            let earlier = attempt.answer.parse::<i128>().ok();
+            let refusal = match attempt.verdict {
+                Verdict::Right => Some(Refusal::AlreadySolved {
+                    answer: attempt.answer.clone(),
+                }),
+                Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow if attempt.answer == answer => {
+                    Some(Refusal::KnownWrong)
+                }
+                Verdict::TooHigh if value.zip(earlier).is_some_and(|(v, e)| v >= e) => {
+                    Some(Refusal::AtLeast {
+                        too_high: attempt.answer.clone(),
+                    })
+                }
+                Verdict::TooLow if value.zip(earlier).is_some_and(|(v, e)| v <= e) => {
+                    Some(Refusal::AtMost {
+                        too_low: attempt.answer.clone(),
+                    })
+                }
+                _ => None,
+            };
+            if refusal.is_some() {
+                return refusal;
+            }
+        }
+
+        None
+    }
+
+    /// Appends `attempt` to the file and to the in-memory list.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            attempt.at,
+            attempt.year,
+            attempt.day,
+            attempt.part,
+            attempt.verdict.tag(),
+            attempt.answer
+        )?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(6, '\t');
+    Some(Attempt {
+        at: fields.next()?.parse().ok()?,
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: Verdict::from_tag(fields.next()?)?,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+/// The result of [`Submitter::submit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The ledger ruled the answer out, so nothing was sent.
+    Refused(Refusal),
+    Submitted(Verdict),
+}
+
+/// Posts answers to the puzzle server, consulting and updating a
+/// [`Ledger`] on the way.
+#[derive(Debug)]
+pub struct Submitter {
+    base_url: String,
+    session: Option<String>,
+    ledger: Ledger,
+}
+
+impl Submitter {
+    pub fn new<S: Into<String>>(base_url: S, ledger: Ledger) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: None,
+            ledger,
+        }
+    }
+
+    /// Same server and session settings as
+    /// [`Fetcher::from_env`](crate::fetch::Fetcher::from_env), with the
+    /// ledger at [`Ledger::default_path`].
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let mut submitter = Self::new(base_url, Ledger::open(Ledger::default_path())?);
+        submitter.session = http::session_from_env();
+        Ok(submitter)
+    }
+
+    pub fn with_session<S: Into<String>>(mut self, token: S) -> Self {
+        self.session = Some(token.into());
+        self
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// Submits `answer` unless the ledger already rules it out, and records
+    /// the attempt either way: refused, failed, or whatever the server said.
+    pub fn submit(&mut self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome> {
+        if let Some(refusal) = self.ledger.check(year, day, part, answer) {
+            self.record(year, day, part, answer, Verdict::Refused)?;
+            return Ok(Outcome::Refused(refusal));
+        }
+
+        match self.post(year, day, part, answer) {
+            Ok(verdict) => {
+                self.record(year, day, part, answer, verdict.clone())?;
+                Ok(Outcome::Submitted(verdict))
+            }
+            Err(err) => {
+                self.record(year, day, part, answer, Verdict::Failed)?;
+                Err(err)
+            }
+        }
+    }
+
+    fn post(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let url = self.url(year, day);
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| http::missing_session(&url))?;
+        let form = format!("level={}&answer={}", part, url_encode(answer));
+        let response = http::post_form(&url, session, &form)?;
+        if response.status != 200 {
+            return Err(Error::fetch(
+                &url,
+                format!("server answered {}", response.status),
+            ));
+        }
+
+        Ok(Verdict::from_page(&response.body))
+    }
+
+    fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<()> {
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.ledger.record(Attempt {
+            at,
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        })
+    }
+}
+
+fn url_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+
+    const RIGHT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>";
+    const TOO_LOW: &str =
+        "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer. If you're stuck...</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+    const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc_ledger_{}_{}", name, std::process::id()));
+            let _ = fs::remove_file(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            at: 1_700_000_000,
+            year: 2025,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_page() {
+        assert_eq!(Verdict::from_page(RIGHT), Verdict::Right);
+        assert_eq!(Verdict::from_page(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(Verdict::from_page(TOO_LOW), Verdict::TooLow);
+        assert_eq!(Verdict::from_page(WRONG), Verdict::Wrong);
+        assert_eq!(
+            Verdict::from_page(TOO_RECENT),
+            Verdict::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(Verdict::from_page(WRONG_LEVEL), Verdict::WrongLevel);
+        assert_eq!(Verdict::from_page("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_ledger_round_trip() {
+        let file = TempFile::new("round_trip");
+        let mut ledger = Ledger::open(&file.0).unwrap();
+        ledger.record(attempt(1, "123", Verdict::TooLow)).unwrap();
+        ledger
+            .record(attempt(1, "abc def", Verdict::Wrong))
+            .unwrap();
+        ledger
+            .record(attempt(2, "7", Verdict::RateLimited(None)))
+            .unwrap();
+
+        let reopened = Ledger::open(&file.0).unwrap();
+        assert_eq!(reopened.attempts(2025, 1, 1).count(), 2);
+        assert_eq!(
+            reopened.attempts(2025, 1, 2).collect::<Vec<_>>(),
+            vec![&attempt(2, "7", Verdict::RateLimited(None))]
+        );
+
+        fs::write(&file.0, "1\t2025\t1\t1\tmaybe\t5\n").unwrap();
+        assert!(matches!(
+            Ledger::open(&file.0),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_ledger_check() {
+        let file = TempFile::new("check");
+        let mut ledger = Ledger::open(&file.0).unwrap();
+        ledger.record(attempt(1, "500", Verdict::TooHigh)).unwrap();
+        ledger.record(attempt(1, "100", Verdict::TooLow)).unwrap();
+        ledger.record(attempt(1, "250", Verdict::Wrong)).unwrap();
+        ledger
+            .record(attempt(1, "260", Verdict::RateLimited(None)))
+            .unwrap();
+        ledger.record(attempt(1, "270", Verdict::Refused)).unwrap();
+        ledger.record(attempt(1, "280", Verdict::Failed)).unwrap();
+
+        let check = |answer| ledger.check(2025, 1, 1, answer);
+        assert_eq!(check("250"), Some(Refusal::KnownWrong));
+        assert_eq!(
+            check("500"),
+            Some(Refusal::KnownWrong),
+            "an exact repeat is reported as such"
+        );
+        assert_eq!(
+            check("501"),
+            Some(Refusal::AtLeast {
+                too_high: "500".to_string()
+            })
+        );
+        assert_eq!(
+            check("99"),
+            Some(Refusal::AtMost {
+                too_low: "100".to_string()
+            })
+        );
+        assert_eq!(check("260"), None, "rate limited answers were never judged");
+        assert_eq!(check("270"), None);
+        assert_eq!(check("280"), None);
+        assert_eq!(check("300"), None);
+        assert_eq!(check("not a number"), None);
+        assert_eq!(ledger.check(2025, 1, 2, "501"), None);
+
+        assert_eq!(ledger.stars(2025, 1), 0);
+        ledger.record(attempt(1, "300", Verdict::Right)).unwrap();
+        assert_eq!(
+            ledger.check(2025, 1, 1, "301"),
+            Some(Refusal::AlreadySolved {
+                answer: "300".to_string()
+            })
+        );
+        assert_eq!(ledger.stars(2025, 1), 1);
+    }
+
+    #[test]
+    fn test_submit_against_stub() {
+        let file = TempFile::new("submit");
+        let (url, requests) = stub::serve(vec![(200, TOO_HIGH), (200, RIGHT)]);
+        let mut submitter =
+            Submitter::new(url, Ledger::open(&file.0).unwrap()).with_session("abc123");
+
+        let outcome = submitter.submit(2025, 3, 2, "500").unwrap();
+        assert_eq!(outcome, Outcome::Submitted(Verdict::TooHigh));
+
+        // refused locally, the stub only has two responses
+        let outcome = submitter.submit(2025, 3, 2, "600").unwrap();
+        assert!(matches!(outcome, Outcome::Refused(Refusal::AtLeast { .. })));
+
+        let outcome = submitter.submit(2025, 3, 2, "450").unwrap();
+        assert_eq!(outcome, Outcome::Submitted(Verdict::Right));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2025/day/3/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=500"));
+
+        let ledger = Ledger::open(&file.0).unwrap();
+        let verdicts: Vec<_> = ledger
+            .attempts(2025, 3, 2)
+            .map(|a| (a.answer.as_str(), a.verdict.clone()))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                ("500", Verdict::TooHigh),
+                ("600", Verdict::Refused),
+                ("450", Verdict::Right)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_submit_records_failures() {
+        let file = TempFile::new("failures");
+        let (url, _) = stub::serve(vec![(500, "")]);
+
+        // no session
+        let mut submitter = Submitter::new(url.as_str(), Ledger::open(&file.0).unwrap());
+        assert!(submitter.submit(2025, 4, 1, "10").is_err());
+
+        let mut submitter =
+            Submitter::new(url, Ledger::open(&file.0).unwrap()).with_session("abc123");
+        assert!(matches!(
+            submitter.submit(2025, 4, 1, "10"),
+            Err(Error::Fetch { .. })
+        ));
+
+        let ledger = Ledger::open(&file.0).unwrap();
+        let verdicts: Vec<_> = ledger
+            .attempts(2025, 4, 1)
+            .map(|a| a.verdict.clone())
+            .collect();
+        assert_eq!(verdicts, vec![Verdict::Failed, Verdict::Failed]);
+        assert_eq!(ledger.check(2025, 4, 1, "10"), None);
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(url_encode("12345"), "12345");
+        assert_eq!(url_encode("a,b c&d"), "a%2Cb%20c%26d");
+    }
+}
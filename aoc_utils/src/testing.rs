@@ -0,0 +1,422 @@
+//! Random inputs for property and differential tests. Failing inputs are
+//! shrunk to a minimal counterexample before being reported:
+//!
+//! ```
+//! use aoc_utils::testing::{self, Check};
+//!
+//! Check::new().differential(
+//!     |rng| testing::digits(rng, 1..=20, 0..=9),
+//!     |digits| digits.iter().max().copied(),
+//!     |digits| digits.iter().copied().reduce(u32::max),
+//! );
+//! ```
+
+use crate::Grid;
+use crate::geom::Point3;
+use std::env;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Overrides the seed every [`Check`] starts from, to replay a failure.
+pub const SEED_VAR: &str = "AOC_TEST_SEED";
+
+const DEFAULT_SEED: u64 = 20251201;
+const DEFAULT_CASES: usize = 256;
+
+/// A small seeded generator (splitmix64). Not for anything but tests.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn between(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        match (hi - lo).checked_add(1) {
+            // multiply-shift keeps the bias negligible without a loop
+            Some(span) => lo + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = range.into_inner();
+        self.between(lo as u64..=hi as u64) as usize
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(0..=items.len() - 1)]
+    }
+}
+
+/// A digit string as digit values, e.g. a row of batteries.
+pub fn digits(rng: &mut Rng, len: RangeInclusive<usize>, digits: RangeInclusive<u32>) -> Vec<u32> {
+    let (lo, hi) = digits.into_inner();
+    (0..rng.index(len))
+        .map(|_| rng.between(lo as u64..=hi as u64) as u32)
+        .collect()
+}
+
+/// Inclusive `(start, end)` ranges with `start <= end <= max` and at most
+/// `max_width` values each.
+pub fn ranges(
+    rng: &mut Rng,
+    count: RangeInclusive<usize>,
+    max: u64,
+    max_width: u64,
+) -> Vec<(u64, u64)> {
+    (0..rng.index(count))
+        .map(|_| {
+            let start = rng.between(0..=max);
+            let width = rng.between(0..=max_width.saturating_sub(1));
+            (start, start.saturating_add(width).min(max))
+        })
+        .collect()
+}
+
+/// A grid whose cells are drawn from `alphabet`.
+pub fn grid(
+    rng: &mut Rng,
+    height: RangeInclusive<usize>,
+    width: RangeInclusive<usize>,
+    alphabet: &[char],
+) -> Grid<char> {
+    let (height, width) = (rng.index(height), rng.index(width));
+    let cells = (0..height * width).map(|_| *rng.pick(alphabet)).collect();
+    Grid::new(width, height, cells).expect("cell count matches the size")
+}
+
+/// Points with every coordinate in `0..=max`.
+pub fn points3(rng: &mut Rng, count: RangeInclusive<usize>, max: u64) -> Vec<Point3<u64>> {
+    (0..rng.index(count))
+        .map(|_| {
+            Point3::new(
+                rng.between(0..=max),
+                rng.between(0..=max),
+                rng.between(0..=max),
+            )
+        })
+        .collect()
+}
+
+/// Smaller versions of a value, tried in order when minimizing a failure.
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+                smaller.dedup();
+                smaller.retain(|n| n < self);
+                smaller
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_shrink_signed {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self - self.signum()];
+                smaller.dedup();
+                smaller.retain(|n| n.unsigned_abs() < self.unsigned_abs());
+                smaller
+            }
+        }
+    )*};
+}
+
+impl_shrink_unsigned!(u8, u16, u32, u64, usize);
+impl_shrink_signed!(i8, i16, i32, i64, isize);
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    // drop halves, then quarters, ... then single items, then shrink items
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = Vec::new();
+
+        let mut chunk = self.len();
+        while chunk > 0 {
+            for start in (0..self.len()).step_by(chunk) {
+                let mut removed = self[..start].to_vec();
+                removed.extend_from_slice(&self[(start + chunk).min(self.len())..]);
+                smaller.push(removed);
+            }
+            chunk /= 2;
+        }
+
+        for (i, item) in self.iter().enumerate() {
+            for replacement in item.shrink() {
+                let mut replaced = self.clone();
+                replaced[i] = replacement;
+                smaller.push(replaced);
+            }
+        }
+
+        smaller
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        self.chars()
+            .collect::<Vec<_>>()
+            .shrink()
+            .into_iter()
+            .map(|chars| chars.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Point3<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+        let xs = x
+            .shrink()
+            .into_iter()
+            .map(|x| Point3::new(x, y.clone(), z.clone()));
+        let ys = y
+            .shrink()
+            .into_iter()
+            .map(|y| Point3::new(x.clone(), y, z.clone()));
+        let zs = z
+            .shrink()
+            .into_iter()
+            .map(|z| Point3::new(x.clone(), y.clone(), z));
+        xs.chain(ys).chain(zs).collect()
+    }
+}
+
+impl<T: Clone> Shrink for Grid<T> {
+    // drop one row or one column at a time, keeping the grid rectangular
+    fn shrink(&self) -> Vec<Self> {
+        let rows: Vec<Vec<T>> = self.rows().map(<[T]>::to_vec).collect();
+        let mut smaller = Vec::new();
+
+        for skip in 0..self.height() {
+            let mut kept = rows.clone();
+            kept.remove(skip);
+            smaller.extend(Grid::from_rows(kept));
+        }
+        for skip in 0..self.width() {
+            let kept = rows
+                .iter()
+                .map(|row| {
+                    let mut row = row.clone();
+                    row.remove(skip);
+                    row
+                })
+                .collect();
+            smaller.extend(Grid::from_rows(kept));
+        }
+
+        smaller
+    }
+}
+
+/// Shrinks `input` for as long as some smaller version still `fails`.
+/// Candidates that panic don't count as failing, so shrinking can't wander
+/// outside what the code under test accepts.
+pub fn minimize<T, F>(mut input: T, fails: F) -> T
+where
+    T: Shrink,
+    F: Fn(&T) -> bool,
+{
+    let still_fails =
+        |candidate: &T| panic::catch_unwind(AssertUnwindSafe(|| fails(candidate))).unwrap_or(false);
+
+    while let Some(smaller) = input.shrink().into_iter().find(still_fails) {
+        input = smaller;
+    }
+    input
+}
+
+/// Runs a property over many generated inputs.
+#[derive(Debug, Clone)]
+pub struct Check {
+    cases: usize,
+    seed: u64,
+}
+
+impl Default for Check {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Check {
+    /// 256 cases from a fixed seed, or the one in `AOC_TEST_SEED`.
+    pub fn new() -> Self {
+        let seed = env::var(SEED_VAR)
+            .ok()
+            .and_then(|seed| seed.trim().parse().ok())
+            .unwrap_or(DEFAULT_SEED);
+        Self {
+            cases: DEFAULT_CASES,
+            seed,
+        }
+    }
+
+    pub fn with_cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn find_failure<T, G, F>(&self, mut generate: G, fails: F) -> Option<T>
+    where
+        T: Shrink,
+        G: FnMut(&mut Rng) -> T,
+        F: Fn(&T) -> bool,
+    {
+        let mut rng = Rng::new(self.seed);
+        (0..self.cases)
+            .map(|_| generate(&mut rng))
+            .find(|input| fails(input))
+            .map(|input| minimize(input, fails))
+    }
+
+    /// Panics with a minimal counterexample if `property` is false for any
+    /// generated input.
+    pub fn property<T, G, P>(&self, generate: G, property: P)
+    where
+        T: Shrink + Debug,
+        G: FnMut(&mut Rng) -> T,
+        P: Fn(&T) -> bool,
+    {
+        if let Some(input) = self.find_failure(generate, |input| !property(input)) {
+            panic!("property failed for {:?} (seed {})", input, self.seed);
+        }
+    }
+
+    /// Panics with a minimal counterexample if `left` and `right` ever
+    /// disagree on a generated input.
+    pub fn differential<T, R, G, L, Rt>(&self, generate: G, left: L, right: Rt)
+    where
+        T: Shrink + Debug,
+        R: PartialEq + Debug,
+        G: FnMut(&mut Rng) -> T,
+        L: Fn(&T) -> R,
+        Rt: Fn(&T) -> R,
+    {
+        if let Some(input) = self.find_failure(generate, |input| left(input) != right(input)) {
+            panic!(
+                "implementations disagree on {:?}: {:?} vs {:?} (seed {})",
+                input,
+                left(&input),
+                right(&input),
+                self.seed
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let draws: Vec<_> = (0..10).map(|_| a.between(1..=6)).collect();
+        assert_eq!(draws, (0..10).map(|_| b.between(1..=6)).collect::<Vec<_>>());
+        assert!(draws.iter().all(|d| (1..=6).contains(d)));
+        assert_eq!(a.between(5..=5), 5);
+        a.between(0..=u64::MAX);
+    }
+
+    #[test]
+    fn test_generators_respect_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..50 {
+            let digits = digits(&mut rng, 2..=5, 1..=9);
+            assert!((2..=5).contains(&digits.len()));
+            assert!(digits.iter().all(|d| (1..=9).contains(d)));
+
+            for (start, end) in ranges(&mut rng, 1..=3, 1000, 10) {
+                assert!(start <= end && end <= 1000 && end - start < 10);
+            }
+
+            let grid = grid(&mut rng, 1..=4, 2..=3, &['.', '@']);
+            assert!((1..=4).contains(&grid.height()) && (2..=3).contains(&grid.width()));
+            assert!(grid.iter().all(|(_, c)| *c == '.' || *c == '@'));
+
+            let points = points3(&mut rng, 0..=4, 9);
+            assert!(points.iter().all(|p| p.x <= 9 && p.y <= 9 && p.z <= 9));
+        }
+    }
+
+    #[test]
+    fn test_minimize() {
+        // fails whenever some item is at least 10
+        let minimal = minimize(vec![3, 40, 7, 12], |items| items.iter().any(|&n| n >= 10));
+        assert_eq!(minimal, vec![10]);
+
+        let grid = Grid::from_rows(vec![vec!['.', '#'], vec!['.', '.']]).unwrap();
+        let minimal = minimize(grid, |grid| grid.iter().any(|(_, c)| *c == '#'));
+        assert_eq!(minimal.to_string(), "#");
+    }
+
+    #[test]
+    fn test_minimize_skips_panicking_candidates() {
+        // an empty list would panic, so the counterexample keeps one item
+        let minimal = minimize(vec![5, 6], |items: &Vec<u32>| items[0] < 100);
+        assert_eq!(minimal, vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "implementations disagree on [10]")]
+    fn test_differential_reports_minimal_input() {
+        Check::new().differential(
+            |rng| digits(rng, 0..=8, 0..=99),
+            |items: &Vec<u32>| items.iter().filter(|&&n| n < 10).count(),
+            |items: &Vec<u32>| items.iter().filter(|&&n| n <= 10).count(),
+        );
+    }
+
+    #[test]
+    fn test_property_passes() {
+        Check::new().with_cases(50).property(
+            |rng| ranges(rng, 0..=5, 100, 20),
+            |ranges| ranges.iter().all(|(start, end)| start <= end),
+        );
+    }
+}
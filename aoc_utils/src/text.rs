@@ -0,0 +1,100 @@
+/// Removes the leading whitespace every non-blank line shares, keeping the
+/// alignment between lines, so examples can be indented with the code
+/// around them:
+///
+/// ```
+/// let example = aoc_utils::text::dedent(
+///     "
+///     123 328
+///      45 64
+///     ",
+/// );
+/// assert_eq!(example, "123 328\n 45 64\n");
+/// ```
+///
+/// A newline right after the opening quote is dropped, as is whitespace
+/// after the last newline. Blank lines become empty. Unlike a `"\`
+/// continuation, which only strips the first line's indentation, this
+/// leaves leading spaces that matter (column-aligned input, grids) intact.
+/// Only whitespace that is the same on every line counts as shared: a tab
+/// and a space are different indentation.
+pub fn dedent(text: &str) -> String {
+    let text = text.strip_prefix('\n').unwrap_or(text);
+    // whitespace before the closing quote is indentation, not input
+    let text = match text.rfind('\n') {
+        Some(i) if text[i + 1..].trim().is_empty() => &text[..=i],
+        _ => text,
+    };
+
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .reduce(common_prefix)
+        .unwrap_or("");
+
+    let mut dedented = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if line.ends_with('\n') {
+                dedented.push('\n');
+            }
+        } else {
+            // every non-blank line starts with `indent`
+            dedented.push_str(&line[indent.len()..]);
+        }
+    }
+    dedented
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .char_indices()
+        .zip(b.chars())
+        .take_while(|((_, x), y)| x == y)
+        .last()
+        .map_or(0, |((i, x), _)| i + x.len_utf8());
+    &a[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedent_keeps_alignment() {
+        let text = "
+            123 328  51 64
+             45 64  387 23
+              6 98  215 314
+            *   +   *   +
+        ";
+        assert_eq!(
+            dedent(text),
+            "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +\n"
+        );
+    }
+
+    #[test]
+    fn test_dedent_blank_lines() {
+        assert_eq!(dedent("\n    3-5\n\n    1\n"), "3-5\n\n1\n");
+        assert_eq!(dedent("    a\n  \n    b"), "a\n\nb");
+    }
+
+    #[test]
+    fn test_dedent_without_indentation() {
+        assert_eq!(dedent("a\n b\n"), "a\n b\n");
+        assert_eq!(dedent(""), "");
+    }
+
+    #[test]
+    fn test_dedent_mixed_whitespace() {
+        // nothing in common, so nothing is stripped, and no char boundary
+        // is split
+        assert_eq!(dedent("  x\n\u{3000}y\n"), "  x\n\u{3000}y\n");
+        assert_eq!(dedent(" \ta\n\t b\n"), " \ta\n\t b\n");
+
+        assert_eq!(dedent("\t  a\n\t b\n"), " a\nb\n");
+        assert_eq!(dedent("\u{3000}\u{3000}a\n\u{3000}b\n"), "\u{3000}a\nb\n");
+    }
+}